@@ -0,0 +1,65 @@
+use crate::*;
+
+/// Action performed when a link is activated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Go to external URI.
+    Uri(String),
+    /// Go to named destination ( see Writer::dest ), which may be defined later.
+    GoTo(String),
+}
+
+impl BasicPdfWriter {
+    /// Write link annotation covering rect ( x0, y0, x1, y1 ), result is obj number ( to be added to Page::annots ).
+    /// elem is the Link structure element ( tagged PDF ).
+    pub fn link(&mut self, rect: [f32; 4], action: &Action, elem: Option<usize>) -> usize {
+        let obj = self.obj();
+        let action = match action {
+            Action::Uri(uri) => Dict::new()
+                .with("S", Object::name("URI"))
+                .with("URI", Object::String(uri.as_bytes().to_vec())),
+            Action::GoTo(name) => Dict::new()
+                .with("S", Object::name("GoTo"))
+                .with("D", Object::String(name.as_bytes().to_vec())),
+        };
+        let mut d = Dict::new()
+            .with("Type", Object::name("Annot"))
+            .with("Subtype", Object::name("Link"))
+            .with("Rect", Object::nums(&rect))
+            .with("F", 4)
+            .with("Border", vec![Object::Int(0), Object::Int(0), Object::Int(0)])
+            .with("A", action);
+        if let Some(e) = elem {
+            d.set("StructParent", self.annot_struct_parent(e, obj));
+        }
+        self.write_obj(obj, &Object::Dict(d));
+        obj
+    }
+}
+
+impl BasicPdfWriter {
+    /// Write name tree of destinations, result is obj number ( zero if there are no destinations ).
+    pub fn dests(&mut self) -> usize {
+        if self.dests.is_empty() {
+            return 0;
+        }
+        let dests = std::mem::take(&mut self.dests);
+        let mut names = Vec::new();
+        for (name, (page, y)) in dests {
+            names.push(Object::String(name.into_bytes()));
+            names.push(dest(page, y));
+        }
+        self.add_obj(&Object::Dict(Dict::new().with("Names", names)))
+    }
+}
+
+/// Destination array, position y on page ( obj number ).
+pub fn dest(page: usize, y: Px) -> Object {
+    Object::Array(vec![
+        Object::Ref(page),
+        Object::name("XYZ"),
+        Object::Null,
+        Object::from(y),
+        Object::Null,
+    ])
+}
@@ -0,0 +1,151 @@
+//!# Attachment example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::attach::*;
+//!    let mut w = Writer::default();
+//!    let csv = b"month,total\nJan,100\nFeb,120\n";
+//!    let fs = w.attach(&FileSpec {
+//!        name: "report.csv",
+//!        data: csv,
+//!        mime: "text/csv",
+//!        description: "Report data",
+//!        relationship: Relationship::Source,
+//!        mod_date: None,
+//!    });
+//!    html(&mut w, b"<p>Monthly report, data is attached ").unwrap();
+//!    w.file_annotation(fs, "Report data");
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(14).any(|x| x == b"/EmbeddedFiles"));
+//!
+//!    // An attachment may be the first item on a page.
+//!    let mut w = Writer::default();
+//!    w.b.nocomp = true;
+//!    let fs = w.attach(&FileSpec { name: "a.csv", data: b"a", mime: "text/csv",
+//!        description: "", relationship: Relationship::Data, mod_date: None });
+//!    w.file_annotation(fs, "Data");
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(10).any(|x| x == b"0 -10 Td /"));
+//!    assert!(bytes.windows(16).any(|x| x == b"10 Tf [-1000] TJ"));
+//! ```
+
+use crate::*;
+
+/// Relationship of an associated file to the document ( /AFRelationship ).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Relationship {
+    /// Original source material ( e.g. the data a report was built from ).
+    Source,
+    /// Information used to derive a visual presentation ( e.g. a table or graph ).
+    Data,
+    /// Alternative representation ( e.g. a ZUGFeRD / Factur-X invoice XML ).
+    Alternative,
+    /// Supplemental representation of the original source or data.
+    Supplement,
+    /// Not associated with the document, only attached.
+    None,
+    /// Relationship is not known or cannot be described using the other values.
+    Unspecified,
+}
+
+/// Embedded file specification - file name, data and attributes.
+pub struct FileSpec<'a> {
+    /// File name, e.g. "factur-x.xml".
+    pub name: &'a str,
+    /// File contents.
+    pub data: &'a [u8],
+    /// MIME type, e.g. "text/xml".
+    pub mime: &'a str,
+    /// Description shown by viewers.
+    pub description: &'a str,
+    /// Relationship to the document, files other than Relationship::None are added to the catalog /AF array.
+    pub relationship: Relationship,
+    /// Modification date of the file, default ( None ) is the current time ( see BasicPdfWriter::now ).
+    pub mod_date: Option<PdfDate>,
+}
+
+impl BasicPdfWriter {
+    /// Write an embedded file stream and its file specification, result is obj number of the file specification.
+    pub fn embed_file(&mut self, f: &FileSpec) -> usize {
+        let date = Object::text(&f.mod_date.unwrap_or_else(|| self.now()).pdf());
+        let params = Dict::new()
+            .with("Size", f.data.len())
+            .with("CreationDate", date.clone())
+            .with("ModDate", date)
+            .with("CheckSum", Object::String(md5(f.data).to_vec()));
+        let dict = Dict::new()
+            .with("Type", Object::name("EmbeddedFile"))
+            .with("Subtype", Object::name(f.mime))
+            .with("Params", params);
+        let ef = self.obj();
+        self.stream_obj(ef, &dict, f.data);
+
+        let relationship = match f.relationship {
+            Relationship::Source => "Source",
+            Relationship::Data => "Data",
+            Relationship::Alternative => "Alternative",
+            Relationship::Supplement => "Supplement",
+            Relationship::None | Relationship::Unspecified => "Unspecified",
+        };
+        let mut d = Dict::new()
+            .with("Type", Object::name("Filespec"))
+            .with("F", Object::text(f.name))
+            .with("UF", Object::text(f.name));
+        if !f.description.is_empty() {
+            d.set("Desc", Object::text(f.description));
+        }
+        d.set("AFRelationship", Object::name(relationship));
+        let ef = Dict::new()
+            .with("F", Object::Ref(ef))
+            .with("UF", Object::Ref(ef));
+        d.set("EF", ef);
+        let obj = self.add_obj(&Object::Dict(d));
+
+        self.files.insert(f.name.to_string(), obj);
+        if f.relationship != Relationship::None {
+            self.af.push(obj);
+        }
+        obj
+    }
+
+    /// Write file attachment annotation covering rect, fs is the file specification obj number ( from embed_file ).
+    /// Result is obj number ( to be added to Page::annots ). elem is the Annot structure element ( tagged PDF ).
+    pub fn file_annot(
+        &mut self,
+        rect: [f32; 4],
+        fs: usize,
+        description: &str,
+        elem: Option<usize>,
+    ) -> usize {
+        let obj = self.obj();
+        let mut d = Dict::new()
+            .with("Type", Object::name("Annot"))
+            .with("Subtype", Object::name("FileAttachment"))
+            .with("Rect", Object::nums(&rect))
+            .with("F", 4)
+            .with("FS", Object::Ref(fs))
+            .with("Name", Object::name("PushPin"));
+        if !description.is_empty() {
+            d.set("Contents", Object::text(description));
+        }
+        if let Some(e) = elem {
+            d.set("StructParent", self.annot_struct_parent(e, obj));
+        }
+        self.write_obj(obj, &Object::Dict(d));
+        obj
+    }
+
+    /// Write name tree of embedded files, result is obj number ( zero if there are no files ).
+    pub fn embedded_files(&mut self) -> usize {
+        if self.files.is_empty() {
+            return 0;
+        }
+        // The files are kept for check_conformance.
+        let mut names = Vec::new();
+        for (name, fs) in &self.files {
+            names.push(Object::text(name));
+            names.push(Object::Ref(*fs));
+        }
+        self.add_obj(&Object::Dict(Dict::new().with("Names", names)))
+    }
+}
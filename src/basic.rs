@@ -1,42 +1,236 @@
 use crate::*;
 use format_bytes::write_bytes as wb;
-use std::collections::BTreeSet;
+use std::io::Write;
 
 /// Low level PDF writing.
 pub struct BasicPdfWriter {
-    /// Output buffer.
+    /// Output buffer ( when streaming, holds only the object currently being written ).
     pub b: Vec<u8>,
-    /// Offset in file of each object.
-    pub xref: Vec<usize>,
+    /// Destination for finished objects, if None the whole PDF is kept in b.
+    pub out: Option<Box<dyn Write>>,
+    /// Number of bytes already sent to out.
+    pub written: usize,
+    /// First error ( e.g. returned by out ), returned by finish.
+    pub error: Option<Error>,
+    /// Location of each object.
+    pub xref: Vec<XrefEntry>,
     /// For compressing streams.
     pub comp: flate3::Compressor,
     /// Suppresses compression.
     pub nocomp: bool,
+    /// Obj number of page tree root ( zero if not yet allocated ).
+    pub pages_obj: usize,
+    /// Obj numbers of pages written so far.
+    pub kids: Vec<usize>,
+    /// Pack non-stream objects into object streams and write a cross-reference stream ( PDF 1.5 ).
+    pub compact: bool,
+    /// Objects ( obj number, offset ) in the current object stream.
+    pub os_index: Vec<(usize, usize)>,
+    /// Data for the current object stream.
+    pub os_data: Vec<u8>,
+    /// Maximum number of objects in an object stream, default is 100.
+    pub os_max: usize,
+    /// Position in b where current object started.
+    obj_start: usize,
+    /// Obj number of current object.
+    pub cur_obj: usize,
+    /// Current object is written as is ( not packed into an object stream, strings not encrypted ).
+    pub plain: bool,
+    /// Current object is not packed into an object stream ( e.g. it is a stream ), its strings are encrypted.
+    pub unpacked: bool,
+    /// Encryption state ( set by encrypt ).
+    pub crypt: Option<Crypt>,
+    /// Trailer /ID, default ( None ) is a hash of the document content.
+    pub id: Option<[u8; 16]>,
+    /// Fixed time used in place of the current time ( see deterministic ).
+    pub date: Option<PdfDate>,
+    /// Hash of the output sent to out so far ( for the document ID ).
+    digest: Md5,
+    /// Document outline ( bookmarks ), written by finish.
+    pub outline: Vec<OutlineItem>,
+    /// Named destinations ( page obj number and position ), written by finish.
+    pub dests: BTreeMap<String, (usize, Px)>,
+    /// Page label ranges in page order, written by finish.
+    pub labels: Vec<PageLabel>,
+    /// Page mode, layout, open action and viewer preferences, written by finish.
+    pub viewer: Viewer,
+    /// Write a tagged PDF with a structure tree ( must be set before any output ).
+    pub tagged: bool,
+    /// Structure elements ( tagged PDF ), root element is index 0, written by finish.
+    pub structure: Vec<StructElem>,
+    /// Parent tree entries ( tagged PDF ), indexed by StructParent(s) key.
+    pub parent_tree: Vec<ParentEntry>,
+    /// Obj numbers of top level form fields, written by finish.
+    pub fields: Vec<usize>,
+    /// Radio button groups by name.
+    pub radio_groups: BTreeMap<String, RadioGroup>,
+    /// Obj numbers of Helvetica and ZapfDingbats fonts used by form fields ( zero if not yet written ).
+    pub form_fonts: (usize, usize),
+    /// Signature settings ( if set, the document is signed by finish ).
+    pub signature: Option<Signature>,
+    /// Obj number of the signature dictionary ( zero if there is no signature field ).
+    pub sig_obj: usize,
+    /// A signature field was written before signature was set, so it has no /V ( reported by sign_file ).
+    pub sig_field_unlinked: bool,
+    /// Positions in b of the signature /ByteRange numbers and /Contents hex string ( start and end ), set by write_signature.
+    pub sig_at: Option<(usize, usize, usize)>,
+    /// Embedded files ( name and file specification obj number ), written by finish.
+    pub files: BTreeMap<String, usize>,
+    /// Obj numbers of associated files ( catalog /AF ).
+    pub af: Vec<usize>,
+    /// PDF/A conformance ( must be set before any output ), adds XMP metadata, output intent and document ID.
+    pub conformance: Option<Conformance>,
+    /// Conformance violations found so far ( e.g. fonts that are not embedded ).
+    pub violations: Vec<String>,
+    /// Extra catalog entries ( e.g. added by extensions ), written by finish.
+    pub catalog: Dict,
+    /// If set, an XMP metadata stream is written ( always written for PDF/A ).
+    pub xmp: Option<Xmp>,
+}
+
+/// Cross-reference entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum XrefEntry {
+    /// Object not yet written.
+    Free,
+    /// Offset of object in file.
+    Offset(usize),
+    /// Object stream obj number and index of object within the stream.
+    Packed(usize, usize),
 }
 
 impl Default for BasicPdfWriter {
     fn default() -> Self {
-        let mut b = Vec::new();
-        b.extend_from_slice(b"%PDF-1.4\n");
         Self {
-            b,
+            b: Vec::new(),
+            out: None,
+            written: 0,
+            error: None,
             xref: Vec::new(),
             comp: flate3::Compressor::default(),
             nocomp: false,
+            pages_obj: 0,
+            kids: Vec::new(),
+            compact: false,
+            os_index: Vec::new(),
+            os_data: Vec::new(),
+            os_max: 100,
+            obj_start: 0,
+            cur_obj: 0,
+            plain: false,
+            unpacked: false,
+            crypt: None,
+            id: None,
+            date: None,
+            digest: Md5::new(),
+            outline: Vec::new(),
+            dests: BTreeMap::new(),
+            labels: Vec::new(),
+            viewer: Viewer::default(),
+            tagged: false,
+            structure: Vec::new(),
+            parent_tree: Vec::new(),
+            fields: Vec::new(),
+            radio_groups: BTreeMap::new(),
+            form_fonts: (0, 0),
+            signature: None,
+            sig_obj: 0,
+            sig_field_unlinked: false,
+            sig_at: None,
+            files: BTreeMap::new(),
+            af: Vec::new(),
+            conformance: None,
+            violations: Vec::new(),
+            catalog: Dict::new(),
+            xmp: None,
         }
     }
 }
 
 impl BasicPdfWriter {
+    /// New writer that sends each object to out as soon as it is complete.
+    pub fn new(out: Box<dyn Write>) -> Self {
+        Self {
+            out: Some(out),
+            ..Default::default()
+        }
+    }
+
+    /// Current position in file.
+    pub fn pos(&self) -> usize {
+        self.written + self.b.len()
+    }
+
+    /// Send buffered output to out ( if streaming ).
+    pub fn flush(&mut self) {
+        if let Some(out) = &mut self.out {
+            if self.error.is_none()
+                && let Err(e) = out.write_all(&self.b)
+            {
+                self.error = Some(Error::Io(e));
+            }
+            self.written += self.b.len();
+            self.digest.update(&self.b);
+            self.b.clear();
+        }
+    }
+
+    /// PDF version written in header.
+    pub fn version(&self) -> &'static [u8] {
+        match &self.crypt {
+            Some(c) if c.cipher == Cipher::Aes256 => b"1.7",
+            Some(c) if c.cipher == Cipher::Aes128 => b"1.6",
+            _ if self.compact => b"1.5",
+            _ => b"1.4",
+        }
+    }
+
+    /// Encrypt the PDF using the standard security handler, must be called before any objects are written.
+    pub fn encrypt(&mut self, s: &Security) -> Result<()> {
+        if !self.xref.is_empty() {
+            return Err(Error::Encryption(
+                "encrypt must be called before any output".to_string(),
+            ));
+        }
+        // The ID feeds the RC4 and AES-128 keys, so it is random even in deterministic mode.
+        let id: [u8; 16] = crypt::random(16)?.try_into().unwrap();
+        self.crypt = Some(Crypt::new(s, id)?);
+        self.id = Some(id);
+        Ok(())
+    }
+
+    /// Make the output reproducible, date is used in place of the current time ( default is 1970-01-01 ).
+    /// Encrypted output is never reproducible, the /ID, keys, salts and initialisation vectors are always random.
+    pub fn deterministic(&mut self, date: Option<PdfDate>) {
+        self.date = Some(date.unwrap_or(PdfDate::from_unix(0)));
+    }
+
+    /// Current time, or the fixed time if set.
+    pub fn now(&self) -> PdfDate {
+        self.date.unwrap_or_else(PdfDate::now)
+    }
+
     /// Allocate PDF object number.
     pub fn obj(&mut self) -> usize {
-        self.xref.push(0);
+        self.xref.push(XrefEntry::Free);
         self.xref.len()
     }
 
     /// Start definition of PDF object.
     pub fn start(&mut self, obj_num: usize) {
-        self.xref[obj_num - 1] = self.b.len();
+        if self.pos() == 0 {
+            let version = self.version();
+            let _ = wb!(&mut self.b, b"%PDF-{}\n", version);
+            if self.conformance.is_some() {
+                // Comment with bytes above 127 marks the file as binary.
+                self.b.extend_from_slice(b"%\xe2\xe3\xcf\xd3\n");
+            }
+        }
+        self.obj_start = self.b.len();
+        self.cur_obj = obj_num;
+        self.plain = false;
+        self.unpacked = false;
+        self.xref[obj_num - 1] = XrefEntry::Offset(self.pos());
         let _ = wb!(&mut self.b, b"{} 0 obj\n", obj_num);
     }
 
@@ -49,91 +243,391 @@ impl BasicPdfWriter {
 
     /// End definition of PDF object.
     pub fn end(&mut self) {
-        self.b.extend_from_slice(b"\nendobj\n");
-    }
-
-    /// Finish the PDF by writing a list of pages.
-    pub fn finish(&mut self, pages: &[Page], title: &[u8]) {
-        let mut kids = Vec::new();
-        let pagesobj = self.obj();
-        for p in pages {
-            let contentobj = self.stream(&p.os);
-            let pageobj = self.begin();
-            let _ = wb!(&mut kids, b"{} 0 R ", pageobj);
-            let _ = wb!(
-                &mut self.b,
-                b"<</Type/Page/Parent {} 0 R/MediaBox[0 0 {} {}]/Contents {} 0 R/Resources <<",
-                pagesobj,
-                p.width,
-                p.height,
-                contentobj
-            );
-            self.resource_set(&p.fonts, b"/Font", b"/F");
-            self.resource_set(&p.xobjs, b"/XObject", b"/X");
-            self.b.extend_from_slice(b" >> >>");
-            self.end();
+        if self.packing() {
+            self.pack();
+        } else {
+            self.b.extend_from_slice(b"\nendobj\n");
+            self.flush();
         }
-        self.start(pagesobj);
-        let _ = wb!(
-            &mut self.b,
-            b"<</Type/Pages/Count {}/Kids[{}]>>",
-            pages.len(),
-            kids
-        );
-        self.end();
+    }
 
-        let cat = self.begin();
-        let _ = wb!(&mut self.b, b"<</Type/Catalog/Pages {} 0 R>>", pagesobj);
-        self.end();
+    /// The current object will be packed into an object stream ( see compact ).
+    fn packing(&self) -> bool {
+        self.compact && !self.plain && !self.unpacked
+    }
 
-        let info = self.begin();
-        let _ = wb!(&mut self.b, b"<</Title({})>>", title);
-        self.end();
+    /// Append object to the current object definition.
+    /// Strings are encrypted if the PDF is encrypted, unless the object is plain or packed into an object stream ( which is encrypted as a whole ).
+    pub fn object(&mut self, o: &Object) {
+        self.write_strings(|to, f| o.write_with(to, f));
+    }
+
+    /// Append dictionary entries ( without << and >> ) to the current object definition, strings are encrypted as for object.
+    pub fn entries(&mut self, d: &Dict) {
+        self.write_strings(|to, f| d.write_entries_with(to, f));
+    }
+
+    /// Call w with the output and a string encoder that encrypts strings if required.
+    fn write_strings(&mut self, w: impl FnOnce(&mut Vec<u8>, &mut dyn FnMut(&[u8], &mut Vec<u8>))) {
+        let crypt = match &self.crypt {
+            Some(c) if !self.plain && !self.packing() => Some(c),
+            _ => None,
+        };
+        let (obj, mut error) = (self.cur_obj, None);
+        w(&mut self.b, &mut |s, to| {
+            match crypt.map(|c| c.encrypt(obj, s)) {
+                Some(Ok(e)) => string(&e, to),
+                Some(Err(e)) => {
+                    error.get_or_insert(e);
+                    string(b"", to);
+                }
+                None => string(s, to),
+            }
+        });
+        if let Some(e) = error {
+            self.set_error(e);
+        }
+    }
+
+    /// Move the current object ( which is not a stream ) into the current object stream.
+    fn pack(&mut self) {
+        let mut body = self.b.split_off(self.obj_start);
+        let hlen = body.iter().position(|b| *b == b'\n').unwrap() + 1;
+        body.drain(..hlen);
+        self.os_index.push((self.cur_obj, self.os_data.len()));
+        self.os_data.extend_from_slice(&body);
+        self.os_data.push(b'\n');
+        if self.os_index.len() >= self.os_max {
+            self.write_object_stream();
+        }
+    }
+
+    /// Write the current object stream ( if it is not empty ).
+    pub fn write_object_stream(&mut self) {
+        if self.os_index.is_empty() {
+            return;
+        }
+        let index = std::mem::take(&mut self.os_index);
+        let os_data = std::mem::take(&mut self.os_data);
+        let obj = self.obj();
+        let mut data = Vec::new();
+        for (i, (num, off)) in index.iter().enumerate() {
+            let _ = wb!(&mut data, b"{} {} ", num, off);
+            self.xref[num - 1] = XrefEntry::Packed(obj, i);
+        }
+        let first = data.len();
+        data.extend_from_slice(&os_data);
+        let dict = Dict::new()
+            .with("Type", Object::name("ObjStm"))
+            .with("N", index.len())
+            .with("First", first);
+        self.stream_obj(obj, &dict, &data);
+    }
+
+    /// Obj number of page tree root.
+    pub fn pages_obj(&mut self) -> usize {
+        if self.pages_obj == 0 {
+            self.pages_obj = self.obj();
+        }
+        self.pages_obj
+    }
+
+    /// Write a finished page ( content stream and page object ), result is obj number of page.
+    pub fn page(&mut self, p: &Page) -> usize {
+        let pagesobj = self.pages_obj();
+        let contentobj = self.stream(&p.os);
+        let pageobj = if p.obj == 0 { self.obj() } else { p.obj };
+        self.kids.push(pageobj);
+        let mut d = Dict::new()
+            .with("Type", Object::name("Page"))
+            .with("Parent", Object::Ref(pagesobj))
+            .with("Contents", Object::Ref(contentobj));
+        let media = p
+            .media_box
+            .unwrap_or([0.0, 0.0, p.width as f32, p.height as f32]);
+        for (name, r) in [
+            ("MediaBox", Some(media)),
+            ("CropBox", p.crop_box),
+            ("BleedBox", p.bleed_box),
+            ("TrimBox", p.trim_box),
+            ("ArtBox", p.art_box),
+        ] {
+            if let Some(r) = r {
+                d.set(name, Object::nums(&r));
+            }
+        }
+        if p.rotate != 0 {
+            d.set("Rotate", p.rotate.rem_euclid(360));
+        }
+        for (k, v) in &p.dict.0 {
+            d.set(k, v.clone());
+        }
+        let mut resources = Dict::new();
+        for (name, prefix, set) in [("Font", "F", &p.fonts), ("XObject", "X", &p.xobjs)] {
+            if !set.is_empty() {
+                let mut r = Dict::new();
+                for i in set {
+                    r.set(&format!("{}{}", prefix, i), Object::Ref(*i));
+                }
+                resources.set(name, r);
+            }
+        }
+        d.set("Resources", resources);
+        if self.tagged {
+            let key = self.page_struct_parents(&p.mcids);
+            d.set("StructParents", key);
+            d.set("Tabs", Object::name("S"));
+        }
+        if !p.annots.is_empty() {
+            d.set("Annots", Object::refs(&p.annots));
+        }
+        self.write_obj(pageobj, &Object::Dict(d));
+        pageobj
+    }
+
+    /// Finish the PDF by writing the page tree, catalog and document information.
+    /// Result is the first error ( if any ) that occurred while writing the PDF.
+    pub fn finish(&mut self, info: &DocInfo) -> Result<()> {
+        // Dates are fixed here so the Info dictionary and XMP metadata match.
+        let mut info = info.clone();
+        let created = info.creation_date.unwrap_or_else(|| self.now());
+        info.creation_date = Some(created);
+        info.mod_date = Some(info.mod_date.unwrap_or(created));
+
+        let pagesobj = self.pages_obj();
+        let pages = Dict::new()
+            .with("Type", Object::name("Pages"))
+            .with("Count", self.kids.len())
+            .with("Kids", Object::refs(&self.kids));
+        self.write_obj(pagesobj, &Object::Dict(pages));
+
+        let outlines = self.outlines();
+        let dests = self.dests();
+        let files = self.embedded_files();
+        let labels = self.page_labels();
+        let struct_tree = self.struct_tree();
+        let acroform = self.acroform();
+        self.write_signature();
+        let metadata = if self.conformance.is_some() || self.xmp.is_some() {
+            self.metadata(&info)
+        } else {
+            0
+        };
+        let intent = if self.conformance.is_some() {
+            self.output_intent()
+        } else {
+            0
+        };
+
+        let mut cat = Dict::new()
+            .with("Type", Object::name("Catalog"))
+            .with("Pages", Object::Ref(pagesobj));
+        if outlines != 0 {
+            cat.set("Outlines", Object::Ref(outlines));
+        }
+        self.viewer_entries(&mut cat);
+        if labels != 0 {
+            cat.set("PageLabels", Object::Ref(labels));
+        }
+        if dests != 0 || files != 0 {
+            let mut names = Dict::new();
+            if dests != 0 {
+                names.set("Dests", Object::Ref(dests));
+            }
+            if files != 0 {
+                names.set("EmbeddedFiles", Object::Ref(files));
+            }
+            cat.set("Names", names);
+        }
+        if !self.af.is_empty() {
+            cat.set("AF", Object::refs(&std::mem::take(&mut self.af)));
+        }
+        if acroform != 0 {
+            cat.set("AcroForm", Object::Ref(acroform));
+        }
+        if struct_tree != 0 {
+            cat.set("StructTreeRoot", Object::Ref(struct_tree));
+            cat.set("MarkInfo", Dict::new().with("Marked", true));
+        }
+        if !info.lang.is_empty() {
+            cat.set("Lang", Object::text(&info.lang));
+        }
+        if metadata != 0 {
+            cat.set("Metadata", Object::Ref(metadata));
+        }
+        if intent != 0 {
+            cat.set("OutputIntents", Object::refs(&[intent]));
+        }
+        if let Some(c) = &self.crypt
+            && c.cipher == Cipher::Aes256
+        {
+            let adbe = Dict::new()
+                .with("BaseVersion", Object::name("1.7"))
+                .with("ExtensionLevel", 8);
+            cat.set("Extensions", Dict::new().with("ADBE", adbe));
+        }
+        for (k, v) in &self.catalog.0 {
+            cat.set(k, v.clone());
+        }
+        let cat = self.add_obj(&Object::Dict(cat));
+
+        let info = self.info(&info);
+        self.check_conformance();
+        if self.id.is_none() {
+            // Hash of the content ( which includes the dates ), so identical output has the same ID.
+            let mut h = self.digest.clone();
+            h.update(&self.b);
+            self.id = Some(h.finish());
+        }
+
+        let mut trailer = Dict::new()
+            .with("Root", Object::Ref(cat))
+            .with("Info", Object::Ref(info));
+        if let Some(c) = &self.crypt {
+            let dict = Object::Dict(c.dict.clone());
+            let obj = self.begin();
+            self.plain = true;
+            self.object(&dict);
+            self.end();
+            trailer.set("Encrypt", Object::Ref(obj));
+        }
+        if let Some(id) = self.id {
+            let id = Object::String(id.to_vec());
+            trailer.set("ID", vec![id.clone(), id]);
+        }
+        let startxref = if self.compact {
+            self.xref_stream(&trailer)
+        } else {
+            self.xref_table(&trailer)
+        };
+        let _ = wb!(&mut self.b, b"\nstartxref\n{}\n%%EOF\n", startxref);
+        self.sign_file();
+        self.flush();
+        if let Some(out) = &mut self.out
+            && self.error.is_none()
+            && let Err(e) = out.flush()
+        {
+            self.error = Some(Error::Io(e));
+        }
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Record error, only the first error is kept ( it is returned by finish ).
+    pub fn set_error(&mut self, e: Error) {
+        if self.error.is_none() {
+            self.error = Some(e);
+        }
+    }
 
-        let startxref = self.b.len();
+    /// Write classic cross-reference table and trailer, result is offset of table.
+    fn xref_table(&mut self, trailer: &Dict) -> usize {
+        let startxref = self.pos();
         let xc = self.xref.len() + 1;
         let _ = wb!(&mut self.b, b"xref\n0 {}\n0000000000 65535 f\n", xc);
 
         for i in 0..self.xref.len() {
-            let x = decimal(self.xref[i], 10);
-            let _ = wb!(&mut self.b, b"{} 00000 n\n", x);
+            let _ = match self.xref[i] {
+                XrefEntry::Free => wb!(&mut self.b, b"0000000000 65535 f\n"),
+                XrefEntry::Offset(x) => wb!(&mut self.b, b"{} 00000 n\n", decimal(x, 10)),
+                // Objects are only packed into object streams when compact is set.
+                XrefEntry::Packed(..) => unreachable!("packed object in classic xref table"),
+            };
+            if self.b.len() >= 0x10000 {
+                self.flush();
+            }
         }
+        self.b.extend_from_slice(b"trailer\n");
+        let mut d = Dict::new().with("Size", xc);
+        d.0.extend(trailer.0.iter().cloned());
+        d.write(&mut self.b);
+        startxref
+    }
 
-        let _ = wb!(
-            &mut self.b,
-            b"trailer\n<</Size {}/Root {} 0 R/Info {} 0 R>>",
-            xc,
-            cat,
-            info
-        );
-        let _ = wb!(&mut self.b, b"\nstartxref\n{}\n%%EOF\n", startxref);
+    /// Write cross-reference stream ( which includes the trailer ), result is offset of stream.
+    fn xref_stream(&mut self, trailer: &Dict) -> usize {
+        self.write_object_stream();
+        // The cross-reference stream is not encrypted.
+        let crypt = self.crypt.take();
+        let obj = self.obj();
+        let startxref = self.pos();
+        self.xref[obj - 1] = XrefEntry::Offset(startxref);
+        let mut data = vec![0, 0, 0, 0, 0, 0xff, 0xff];
+        for e in &self.xref {
+            let (t, f2, f3) = match *e {
+                XrefEntry::Free => (0, 0, 0),
+                XrefEntry::Offset(x) => (1, x, 0),
+                XrefEntry::Packed(os, i) => (2, os, i),
+            };
+            data.push(t);
+            data.extend_from_slice(&(f2 as u32).to_be_bytes());
+            data.extend_from_slice(&(f3 as u16).to_be_bytes());
+        }
+        let mut dict = Dict::new()
+            .with("Type", Object::name("XRef"))
+            .with("Size", self.xref.len() + 1)
+            .with("W", vec![Object::Int(1), Object::Int(4), Object::Int(2)]);
+        dict.0.extend(trailer.0.iter().cloned());
+        self.stream_obj(obj, &dict, &data);
+        self.crypt = crypt;
+        startxref
     }
 
-    fn resource_set(&mut self, s: &BTreeSet<usize>, n1: &[u8], n2: &[u8]) {
-        if !s.is_empty() {
-            let _ = wb!(&mut self.b, b"{}<<", n1);
-            for i in s {
-                let _ = wb!(&mut self.b, b"{}{} {} 0 R", n2, i, i);
-            }
-            self.b.extend_from_slice(b">>");
+    /// Write the document information dictionary, result is obj number.
+    pub fn info(&mut self, info: &DocInfo) -> usize {
+        let mut d = Dict::new();
+        for (k, v) in info.fields() {
+            d.set(std::str::from_utf8(k).unwrap(), Object::text(v));
         }
+        let created = info.creation_date.unwrap_or_else(|| self.now());
+        let modified = info.mod_date.unwrap_or(created);
+        d.set("CreationDate", Object::text(&created.pdf()));
+        d.set("ModDate", Object::text(&modified.pdf()));
+        self.add_obj(&Object::Dict(d))
     }
 
     /// Output a stream (possibly compressed ), result is obj number.
     pub fn stream(&mut self, data: &[u8]) -> usize {
-        let obj = self.begin();
+        let obj = self.obj();
+        self.stream_obj(obj, &Dict::new(), data);
+        obj
+    }
+
+    /// Output a stream (possibly compressed ) as object obj, dict has any extra dictionary entries.
+    pub fn stream_obj(&mut self, obj: usize, dict: &Dict, data: &[u8]) {
         if self.nocomp {
-            let _ = wb!(&mut self.b, b"<</Length {}>>stream\n", data.len());
-            self.b.extend_from_slice(data);
+            self.raw_stream_obj(obj, dict, data);
         } else {
             let cb: Vec<u8> = self.comp.deflate(data);
-            // Fl is abbreviation for FlateDecode
-            let _ = wb!(&mut self.b, b"<</Filter/Fl/Length {}>>stream\n", cb.len());
-            self.b.extend_from_slice(&cb);
+            let dict = dict.clone().with("Filter", Object::name("FlateDecode"));
+            self.raw_stream_obj(obj, &dict, &cb);
         }
+    }
+
+    /// Output a stream ( without compression ) as object obj, dict has any extra dictionary entries.
+    pub fn raw_stream_obj(&mut self, obj: usize, dict: &Dict, data: &[u8]) {
+        self.start(obj);
+        self.unpacked = true;
+        let enc;
+        let data = match self.crypt.as_ref().map(|c| c.encrypt(obj, data)) {
+            Some(Ok(e)) => {
+                enc = e;
+                &enc[..]
+            }
+            Some(Err(e)) => {
+                self.set_error(e);
+                &[]
+            }
+            None => data,
+        };
+        let dict = dict.clone().with("Length", data.len());
+        self.object(&Object::Dict(dict));
+        self.b.extend_from_slice(b"stream\n");
+        self.b.extend_from_slice(data);
         self.b.extend_from_slice(b"\nendstream");
         self.end();
-        obj
     }
 }
 
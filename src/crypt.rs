@@ -0,0 +1,534 @@
+//!# Encryption example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::crypt::*;
+//!    let mut w = Writer::default();
+//!    w.encrypt(&Security {
+//!        cipher: Cipher::Aes256,
+//!        user_password: "user".to_string(),
+//!        owner_password: "owner".to_string(),
+//!        permissions: PRINT,
+//!    })
+//!    .unwrap();
+//!    html(&mut w, b"<p>Payslip").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(10).any(|x| x == b"/CFM/AESV3"));
+//! ```
+
+use crate::*;
+use aes::cipher::block_padding::{NoPadding, Pkcs7};
+use aes::cipher::{BlockCipher, BlockEncryptMut, KeyInit, KeyIvInit};
+use sha2::Digest;
+
+/// Permission to print.
+pub const PRINT: u32 = 1 << 2;
+/// Permission to modify contents.
+pub const MODIFY: u32 = 1 << 3;
+/// Permission to copy text and graphics.
+pub const COPY: u32 = 1 << 4;
+/// Permission to add or modify annotations and fill form fields.
+pub const ANNOTATE: u32 = 1 << 5;
+/// Permission to fill form fields.
+pub const FILL: u32 = 1 << 8;
+/// Permission to extract text and graphics for accessibility.
+pub const EXTRACT: u32 = 1 << 9;
+/// Permission to assemble the document ( insert, rotate or delete pages ).
+pub const ASSEMBLE: u32 = 1 << 10;
+/// Permission to print at full quality.
+pub const PRINT_HIGH: u32 = 1 << 11;
+/// All permissions.
+pub const ALL: u32 = PRINT | MODIFY | COPY | ANNOTATE | FILL | EXTRACT | ASSEMBLE | PRINT_HIGH;
+
+/// Encryption algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cipher {
+    /// RC4 with 128 bit key ( revision 3, for legacy readers ).
+    Rc4,
+    /// AES with 128 bit key ( revision 4 ).
+    Aes128,
+    /// AES with 256 bit key ( revision 6 ).
+    Aes256,
+}
+
+/// Standard security handler settings.
+#[derive(Clone)]
+pub struct Security {
+    /// Encryption algorithm.
+    pub cipher: Cipher,
+    /// Password needed to open the document ( may be empty ).
+    pub user_password: String,
+    /// Password that gives full access, if empty the user password is used.
+    pub owner_password: String,
+    /// Permission flags ( e.g. PRINT | COPY ) granted to the user.
+    pub permissions: u32,
+}
+
+/// Add the standard crypt filter ( used for both streams and strings ) to the encryption dictionary.
+fn crypt_filter(dict: &mut Dict, method: &str, length: i32) {
+    let cf = Dict::new()
+        .with("CFM", Object::name(method))
+        .with("AuthEvent", Object::name("DocOpen"))
+        .with("Length", length);
+    dict.set("CF", Dict::new().with("StdCF", cf));
+    dict.set("StmF", Object::name("StdCF"));
+    dict.set("StrF", Object::name("StdCF"));
+}
+
+/// Encryption state used by BasicPdfWriter.
+pub struct Crypt {
+    /// Encryption algorithm.
+    pub cipher: Cipher,
+    /// File encryption key.
+    pub key: Vec<u8>,
+    /// The encryption dictionary.
+    pub dict: Dict,
+    /// First element of trailer /ID.
+    pub id: [u8; 16],
+}
+
+impl Crypt {
+    /// Compute keys and encryption dictionary. id is the first element of trailer /ID.
+    /// Error if the operating system cannot supply random numbers.
+    pub fn new(s: &Security, id: [u8; 16]) -> Result<Self> {
+        let p = (s.permissions | 0xFFFFF0C0) as i32;
+        let owner = if s.owner_password.is_empty() {
+            &s.user_password
+        } else {
+            &s.owner_password
+        };
+        let mut dict = Dict::new().with("Filter", Object::name("Standard"));
+        let key = if s.cipher == Cipher::Aes256 {
+            let key = random(32)?;
+            let salts = random(32)?;
+            let [u, ue, o, oe] = r6_values(sasl(&s.user_password), sasl(owner), &key, &salts);
+            let mut perms = Vec::new();
+            perms.extend_from_slice(&p.to_le_bytes());
+            perms.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, b'T', b'a', b'd', b'b']);
+            perms.extend_from_slice(&random(4)?);
+            let perms = aes_ecb(&key, &perms);
+            dict.set("V", 5);
+            dict.set("R", 6);
+            dict.set("Length", 256);
+            crypt_filter(&mut dict, "AESV3", 32);
+            for (k, v) in [("O", o), ("U", u), ("OE", oe), ("UE", ue), ("Perms", perms)] {
+                dict.set(k, Object::String(v));
+            }
+            key
+        } else {
+            let user = pad(&s.user_password);
+            let o = owner_value(&pad(owner), &user);
+            let mut h = Md5::new();
+            h.update(&user);
+            h.update(&o);
+            h.update(&p.to_le_bytes());
+            h.update(&id);
+            let mut key = h.finish().to_vec();
+            for _ in 0..50 {
+                key = md5(&key).to_vec();
+            }
+            let mut h = Md5::new();
+            h.update(&PAD);
+            h.update(&id);
+            let mut u = rc4_loop(&key, &h.finish());
+            u.extend_from_slice(&[0; 16]);
+            if s.cipher == Cipher::Rc4 {
+                dict.set("V", 2);
+                dict.set("R", 3);
+                dict.set("Length", 128);
+            } else {
+                dict.set("V", 4);
+                dict.set("R", 4);
+                dict.set("Length", 128);
+                crypt_filter(&mut dict, "AESV2", 16);
+            }
+            dict.set("O", Object::String(o));
+            dict.set("U", Object::String(u));
+            key
+        };
+        dict.set("P", p);
+        Ok(Self {
+            cipher: s.cipher,
+            key,
+            dict,
+            id,
+        })
+    }
+
+    /// Encrypt data belonging to object obj.
+    pub fn encrypt(&self, obj: usize, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(match self.cipher {
+            Cipher::Aes256 => {
+                let iv = random(16)?;
+                let mut result = iv.clone();
+                result.extend_from_slice(&aes_cbc(&self.key, &iv, data, true));
+                result
+            }
+            _ => {
+                let mut h = Md5::new();
+                h.update(&self.key);
+                h.update(&(obj as u32).to_le_bytes()[0..3]);
+                h.update(&[0, 0]);
+                if self.cipher == Cipher::Aes128 {
+                    h.update(b"sAlT");
+                    let key = h.finish();
+                    let iv = random(16)?;
+                    let mut result = iv.clone();
+                    result.extend_from_slice(&aes_cbc(&key, &iv, data, true));
+                    result
+                } else {
+                    rc4(&h.finish(), data)
+                }
+            }
+        })
+    }
+}
+
+/// Padding string for passwords ( revisions 2 to 4 ).
+const PAD: [u8; 32] = [
+    0x28, 0xBF, 0x4E, 0x5E, 0x4E, 0x75, 0x8A, 0x41, 0x64, 0x00, 0x4E, 0x56, 0xFF, 0xFA, 0x01, 0x08,
+    0x2E, 0x2E, 0x00, 0xB6, 0xD0, 0x68, 0x3E, 0x80, 0x2F, 0x0C, 0xA9, 0xFE, 0x64, 0x53, 0x69, 0x7A,
+];
+
+/// Password encoded ( as Latin-1 ) and padded to 32 bytes.
+fn pad(pw: &str) -> Vec<u8> {
+    let mut result: Vec<u8> = pw
+        .chars()
+        .map(|c| if (c as u32) < 256 { c as u8 } else { b'?' })
+        .take(32)
+        .collect();
+    let n = result.len();
+    result.extend_from_slice(&PAD[..32 - n]);
+    result
+}
+
+/// Password as UTF-8 truncated to 127 bytes ( revision 6 ).
+fn sasl(pw: &str) -> &[u8] {
+    let mut n = pw.len().min(127);
+    while !pw.is_char_boundary(n) {
+        n -= 1;
+    }
+    &pw.as_bytes()[..n]
+}
+
+/// Compute /O value ( algorithm 3 ).
+fn owner_value(owner: &[u8], user: &[u8]) -> Vec<u8> {
+    let mut key = md5(owner);
+    for _ in 0..50 {
+        key = md5(&key);
+    }
+    rc4_loop(&key, user)
+}
+
+/// RC4 encrypt 20 times with key modified by xor with 0..19.
+fn rc4_loop(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut result = data.to_vec();
+    for i in 0..20 {
+        let k: Vec<u8> = key.iter().map(|b| b ^ i).collect();
+        result = rc4(&k, &result);
+    }
+    result
+}
+
+/// Compute /U, /UE, /O and /OE values ( revision 6 ).
+/// salts holds the user validation, user key, owner validation and owner key salts ( 8 bytes each ).
+fn r6_values(user: &[u8], owner: &[u8], key: &[u8], salts: &[u8]) -> [Vec<u8>; 4] {
+    let (uvs, uks, ovs, oks) = (&salts[0..8], &salts[8..16], &salts[16..24], &salts[24..32]);
+    let mut u = hash_r6(user, uvs, b"");
+    u.extend_from_slice(uvs);
+    u.extend_from_slice(uks);
+    let ue = aes_cbc(&hash_r6(user, uks, b""), &[0; 16], key, false);
+    let mut o = hash_r6(owner, ovs, &u);
+    o.extend_from_slice(ovs);
+    o.extend_from_slice(oks);
+    let oe = aes_cbc(&hash_r6(owner, oks, &u), &[0; 16], key, false);
+    [u, ue, o, oe]
+}
+
+/// Hash password ( algorithm 2.B, revision 6 ).
+fn hash_r6(pw: &[u8], salt: &[u8], udata: &[u8]) -> Vec<u8> {
+    let mut k = sha256(&[pw, salt, udata].concat()).to_vec();
+    let mut round = 0;
+    loop {
+        let k1 = [pw, &k, udata].concat().repeat(64);
+        let e = aes_cbc(&k[0..16], &k[16..32], &k1, false);
+        let m = e[0..16].iter().map(|b| *b as u32).sum::<u32>() % 3;
+        k = match m {
+            0 => sha256(&e).to_vec(),
+            1 => sha512(&e, true),
+            _ => sha512(&e, false),
+        };
+        // Stop after at least 64 rounds, once the last byte of e is at most ( rounds done ) - 32.
+        if round >= 63 && (*e.last().unwrap() as u32) + 31 <= round {
+            break;
+        }
+        round += 1;
+    }
+    k.truncate(32);
+    k
+}
+
+/// Random bytes from the operating system ( for keys, salts and initialisation vectors ).
+pub(crate) fn random(n: usize) -> Result<Vec<u8>> {
+    let mut result = vec![0; n];
+    getrandom::getrandom(&mut result).map_err(|e| {
+        Error::Encryption(format!("no random numbers from the operating system: {}", e))
+    })?;
+    Ok(result)
+}
+
+/// RC4 encryption ( or decryption ).
+pub fn rc4(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut s: Vec<u8> = (0..=255).collect();
+    let mut j: u8 = 0;
+    for i in 0..256 {
+        j = j.wrapping_add(s[i]).wrapping_add(key[i % key.len()]);
+        s.swap(i, j as usize);
+    }
+    let (mut i, mut j) = (0u8, 0u8);
+    data.iter()
+        .map(|b| {
+            i = i.wrapping_add(1);
+            j = j.wrapping_add(s[i as usize]);
+            s.swap(i as usize, j as usize);
+            b ^ s[s[i as usize].wrapping_add(s[j as usize]) as usize]
+        })
+        .collect()
+}
+
+/// MD5 hash.
+pub fn md5(data: &[u8]) -> [u8; 16] {
+    let mut h = Md5::new();
+    h.update(data);
+    h.finish()
+}
+
+/// Incremental MD5 hash.
+#[derive(Clone, Default)]
+pub struct Md5(::md5::Md5);
+
+impl Md5 {
+    /// New hash.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add data to hash.
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    /// Finish hash.
+    pub fn finish(self) -> [u8; 16] {
+        self.0.finalize().into()
+    }
+}
+
+/// SHA-256 hash.
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    sha2::Sha256::digest(data).into()
+}
+
+/// SHA-384 ( if short is true ) or SHA-512 hash.
+pub fn sha512(data: &[u8], short: bool) -> Vec<u8> {
+    if short {
+        sha2::Sha384::digest(data).to_vec()
+    } else {
+        sha2::Sha512::digest(data).to_vec()
+    }
+}
+
+/// AES CBC encryption with key of 16 or 32 bytes, pkcs adds PKCS#5 padding.
+pub fn aes_cbc(key: &[u8], iv: &[u8], data: &[u8], pkcs: bool) -> Vec<u8> {
+    fn enc<C: BlockEncryptMut + BlockCipher + KeyInit>(
+        key: &[u8],
+        iv: &[u8],
+        data: &[u8],
+        pkcs: bool,
+    ) -> Vec<u8> {
+        let c = cbc::Encryptor::<C>::new_from_slices(key, iv).unwrap();
+        if pkcs {
+            c.encrypt_padded_vec_mut::<Pkcs7>(data)
+        } else {
+            c.encrypt_padded_vec_mut::<NoPadding>(data)
+        }
+    }
+    if key.len() == 32 {
+        enc::<aes::Aes256>(key, iv, data, pkcs)
+    } else {
+        enc::<aes::Aes128>(key, iv, data, pkcs)
+    }
+}
+
+/// AES ECB encryption of a whole number of blocks.
+pub fn aes_ecb(key: &[u8], data: &[u8]) -> Vec<u8> {
+    // Each block is CBC encrypted on its own with a zero IV.
+    data.chunks(16)
+        .flat_map(|b| aes_cbc(key, &[0; 16], b, false))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn md5_rfc1321() {
+        for (s, h) in [
+            ("", "d41d8cd98f00b204e9800998ecf8427e"),
+            ("a", "0cc175b9c0f1b6a831c399e269772661"),
+            ("abc", "900150983cd24fb0d6963f7d28e17f72"),
+            ("message digest", "f96b697d7cb7938d525a2f31aaf161d0"),
+            (
+                "abcdefghijklmnopqrstuvwxyz",
+                "c3fcd3d76192e4007dfb496cca67e13b",
+            ),
+            (
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+                "d174ab98d277d9f5a5611c2c9f419d9f",
+            ),
+            (
+                "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                "57edf4a22be3c955ac49da2e2107b67a",
+            ),
+        ] {
+            assert_eq!(md5(s.as_bytes()).to_vec(), hex(h), "{s}");
+            // Same result when the data is added in pieces.
+            let mut m = Md5::new();
+            for c in s.as_bytes().chunks(7) {
+                m.update(c);
+            }
+            assert_eq!(m.finish().to_vec(), hex(h), "{s}");
+        }
+    }
+
+    #[test]
+    fn sha_fips180() {
+        let abc = b"abc";
+        let two = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+        let four = concat!(
+            "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmno",
+            "ijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu"
+        )
+        .as_bytes();
+        assert_eq!(
+            sha256(b"").to_vec(),
+            hex("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+        assert_eq!(
+            sha256(abc).to_vec(),
+            hex("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert_eq!(
+            sha256(two).to_vec(),
+            hex("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1")
+        );
+        assert_eq!(
+            sha256(&[b'a'; 1000000]).to_vec(),
+            hex("cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0")
+        );
+        assert_eq!(
+            sha512(abc, true),
+            hex(concat!(
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded163",
+                "1a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+            ))
+        );
+        assert_eq!(
+            sha512(four, true),
+            hex(concat!(
+                "09330c33f71147e83d192fc782cd1b4753111b173b3b05d2",
+                "2fa08086e3b0f712fcc7c71a557e2db966c3e9fa91746039"
+            ))
+        );
+        assert_eq!(
+            sha512(b"", false),
+            hex(concat!(
+                "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce",
+                "47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+            ))
+        );
+        assert_eq!(
+            sha512(abc, false),
+            hex(concat!(
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a",
+                "2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+            ))
+        );
+        assert_eq!(
+            sha512(four, false),
+            hex(concat!(
+                "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018",
+                "501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909"
+            ))
+        );
+    }
+
+    #[test]
+    fn aes_fips197() {
+        // Appendix C examples.
+        let pt = hex("00112233445566778899aabbccddeeff");
+        let key: Vec<u8> = (0..32).collect();
+        assert_eq!(
+            aes_ecb(&key[..16], &pt),
+            hex("69c4e0d86a7b0430d8cdb78070b4c55a")
+        );
+        assert_eq!(aes_ecb(&key, &pt), hex("8ea2b7ca516745bfeafc49904b496089"));
+        // CBC with a zero IV is the same as ECB for the first block.
+        assert_eq!(
+            aes_cbc(&key[..16], &[0; 16], &pt, false),
+            hex("69c4e0d86a7b0430d8cdb78070b4c55a")
+        );
+        // A whole block of padding is added to a whole number of blocks.
+        assert_eq!(aes_cbc(&key, &[0; 16], &pt, true).len(), 32);
+    }
+
+    #[test]
+    fn rc4_vectors() {
+        assert_eq!(rc4(b"Key", b"Plaintext"), hex("bbf316e8d940af0ad3"));
+        assert_eq!(rc4(b"Wiki", b"pedia"), hex("1021bf0420"));
+        assert_eq!(
+            rc4(b"Secret", b"Attack at dawn"),
+            hex("45a01f645fc35b383552544b9bf5")
+        );
+        // Decryption is the same as encryption.
+        assert_eq!(rc4(b"Key", &hex("bbf316e8d940af0ad3")), b"Plaintext");
+    }
+
+    #[test]
+    fn r6() {
+        // The user validation salt makes the hash stop on the first round allowed.
+        let salts = [[8; 8], [9; 8], [10; 8], [11; 8]].concat();
+        let key: Vec<u8> = (0..32).collect();
+        let [u, ue, o, oe] = r6_values(b"user", b"owner", &key, &salts);
+        assert_eq!(
+            u,
+            hex(concat!(
+                "1b1e5c8be12eda6a8a3c29718b6a9a95a5c74e2059ba815ef2b1d823f05b9c2f",
+                "08080808080808080909090909090909"
+            ))
+        );
+        assert_eq!(
+            ue,
+            hex("79d6ad6f9342d8cb1e7ca20cc790888013a4324e5033d55a45f118981a4d8ae4")
+        );
+        assert_eq!(
+            o,
+            hex(concat!(
+                "627c0ac36eee6ee03199d5ff982c5a6283cca477613c4def1081f90e0d686189",
+                "0a0a0a0a0a0a0a0a0b0b0b0b0b0b0b0b"
+            ))
+        );
+        assert_eq!(
+            oe,
+            hex("f7299c17b3afffbf5090cc9833d15fd24b483be51ae58175084f3d70aafbf278")
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//!# Error example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::error::Error;
+//!    let mut w = Writer::default();
+//!    // There is no fetcher, so the image cannot be fetched.
+//!    let r = html(&mut w, b"<p>Logo <img src=logo.png>");
+//!    assert!(matches!(r, Err(Error::Fetch(..))));
+//!    // Neither Helvetica ( WinAnsi encoding ) nor the symbol fonts have Chinese characters.
+//!    assert!(matches!(w.text("\u{4e2d}"), Err(Error::Encoding(_, '\u{4e2d}'))));
+//!    // The arrow is in the Symbol font, so the error is for the character after it.
+//!    let e = w.text("\u{2192}\u{4e2d}").unwrap_err();
+//!    assert!(matches!(&e, Error::Encoding(f, '\u{4e2d}') if f == "Helvetica"));
+//!    assert!(matches!(html(&mut w, b"\xff"), Err(Error::Utf8(0))));
+//!    assert!(w.finish().is_ok());
+//! ```
+
+use std::fmt;
+
+/// Error converting html or writing the PDF.
+#[derive(Debug)]
+pub enum Error {
+    /// Error writing output ( see BasicPdfWriter::out ).
+    Io(std::io::Error),
+    /// Source html is not valid UTF-8, value is the byte offset of the invalid data.
+    Utf8(usize),
+    /// Character cannot be encoded using font ( font name, character ).
+    Encoding(String, char),
+    /// Font data is not valid or not supported.
+    Font(String),
+    /// Image or font could not be fetched ( name, reason ).
+    Fetch(String, String),
+    /// Document does not conform to the conformance level ( list of violations ).
+    Conformance(Vec<String>),
+    /// Document could not be signed.
+    Signature(String),
+    /// Document could not be encrypted.
+    Encryption(String),
+}
+
+/// Result with crate Error.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "output error: {}", e),
+            Error::Utf8(at) => write!(f, "source is not valid UTF-8 at byte {}", at),
+            Error::Encoding(font, c) => {
+                write!(f, "character {:?} cannot be encoded using font {}", c, font)
+            }
+            Error::Font(msg) => write!(f, "font error: {}", msg),
+            Error::Fetch(name, reason) => write!(f, "could not fetch {}: {}", name, reason),
+            Error::Conformance(v) => write!(f, "conformance violations: {}", v.join("; ")),
+            Error::Signature(msg) => write!(f, "signing failed: {}", msg),
+            Error::Encryption(msg) => write!(f, "encryption failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
@@ -1,21 +1,106 @@
+//!# Kerning example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    let mut w = Writer::default();
+//!    w.b.nocomp = true;
+//!    html(&mut w, b"<p>AVATAR").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    // Kerning adjustments are written with TJ.
+//!    assert!(bytes.windows(26).any(|x| x == b"[(A)70(V)80(A)120(T)120(AR"));
+//! ```
+//!
+//!# Font metrics example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::font::*;
+//!    let m = helvetica()[0].metrics();
+//!    assert_eq!((m.ascent, m.descent, m.cap_height, m.x_height), (718, -207, 718, 523));
+//!    // Lines are placed using the ascent and descent, so a superscript makes space above its line.
+//!    let mut w = Writer::default();
+//!    w.b.nocomp = true;
+//!    html(&mut w, b"<p>One<p>x<sup>2</sup>").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(15).any(|x| x == b"0 -19 Td (x) Tj"));
+//! ```
+//!
+//!# Symbol fonts example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::font::*;
+//!    // Symbol and ZapfDingbats use their built-in encodings.
+//!    let mut code = Vec::new();
+//!    zapf_dingbats().encode("✓✗☎", &mut code).unwrap();
+//!    symbol().encode("α≤→", &mut code).unwrap();
+//!    assert_eq!(code, [0x33, 0x37, 0x25, 0x61, 0xA3, 0xAE]);
+//!    // Characters the current font cannot encode are written using Writer::symbol_fonts.
+//!    let mut w = Writer::default();
+//!    w.b.nocomp = true;
+//!    html(&mut w, "<p>Passed ✓ Failed ✗".as_bytes()).unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(13).any(|x| x == b"/ZapfDingbats"));
+//!    assert!(bytes.windows(11).any(|x| x == b" 10 Tf (3) "));
+//! ```
+
 use crate::*;
 use crate::basic::BasicPdfWriter;
 use crate::metric::*;
-use format_bytes::write_bytes as wb;
 
 /// Font
 pub trait Font {
     /// Get the PDF object number.
     fn obj(&self) -> usize;
 
-    /// Encode string.
-    fn encode(&self, s: &str, to: &mut Vec<u8>);
+    /// Encode string, characters that cannot be encoded are replaced and the first is reported as an error.
+    fn encode(&self, s: &str, to: &mut Vec<u8>) -> Result<()>;
 
     /// Get char width
     fn width(&self, c: char) -> MPx;
 
+    /// Get vertical metrics and bounding box.
+    fn metrics(&self) -> FontMetrics;
+
+    /// Kerning adjustment for character pair a, b ( in the same units as width, negative moves b closer to a ).
+    fn kern(&self, _a: char, _b: char) -> MPx {
+        0
+    }
+
+    /// Width of string, including kerning.
+    fn text_width(&self, s: &str) -> MPx {
+        let mut width = 0;
+        let mut last = None;
+        for c in s.chars() {
+            width += self.width(c);
+            if let Some(a) = last {
+                width += self.kern(a, c);
+            }
+            last = Some(c);
+        }
+        width
+    }
+
     /// Initialise the font by writing defition to w.
     fn init(&mut self, w: &mut BasicPdfWriter);
+
+    /// Write any deferred font data ( e.g. an embedded subset of the glyphs used ), called by Writer::finish.
+    fn finish(&mut self, _w: &mut BasicPdfWriter) {}
+}
+
+/// Font metrics, in 1/1000 of the font size ( the same units as Font::width ).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FontMetrics {
+    /// Ascender ( height above the baseline of letters such as 'd' ), used for line layout.
+    pub ascent: i32,
+    /// Descender ( depth below the baseline of letters such as 'p', negative ), used for line layout.
+    pub descent: i32,
+    /// Height of capital letters.
+    pub cap_height: i32,
+    /// Height of lower case letters such as 'x'.
+    pub x_height: i32,
+    /// Bounding box of all glyphs [x0 y0 x1 y1].
+    pub bbox: [i32; 4],
 }
 
 /// Font family - normal, bold, italic, bold italic
@@ -23,47 +108,77 @@ pub type FontFamily = [Box<dyn Font>; 4];
 
 /// Helvetica standard font family
 pub fn helvetica() -> FontFamily {
-    let a = StandardFont::make(&HELVETICA0[..], HELVETICA[0]);
-    let b = StandardFont::make(&HELVETICA1[..], HELVETICA[1]);
-    let c = StandardFont::make(&HELVETICA2[..], HELVETICA[2]);
-    let d = StandardFont::make(&HELVETICA3[..], HELVETICA[3]);
+    let a = StandardFont::make(&HELVETICA0[..], &HELVETICA_KERN0[..], HELVETICA_METRICS[0], None, HELVETICA[0]);
+    let b = StandardFont::make(&HELVETICA1[..], &HELVETICA_KERN1[..], HELVETICA_METRICS[1], None, HELVETICA[1]);
+    let c = StandardFont::make(&HELVETICA2[..], &HELVETICA_KERN0[..], HELVETICA_METRICS[2], None, HELVETICA[2]);
+    let d = StandardFont::make(&HELVETICA3[..], &HELVETICA_KERN1[..], HELVETICA_METRICS[3], None, HELVETICA[3]);
     [a, b, c, d]
 }
 
 /// Times standard font family
 pub fn times() -> FontFamily {
-    let a = StandardFont::make(&TIMES0[..], TIMES[0]);
-    let b = StandardFont::make(&TIMES1[..], TIMES[1]);
-    let c = StandardFont::make(&TIMES2[..], TIMES[2]);
-    let d = StandardFont::make(&TIMES3[..], TIMES[3]);
+    let a = StandardFont::make(&TIMES0[..], &TIMES_KERN0[..], TIMES_METRICS[0], None, TIMES[0]);
+    let b = StandardFont::make(&TIMES1[..], &TIMES_KERN1[..], TIMES_METRICS[1], None, TIMES[1]);
+    let c = StandardFont::make(&TIMES2[..], &TIMES_KERN2[..], TIMES_METRICS[2], None, TIMES[2]);
+    let d = StandardFont::make(&TIMES3[..], &TIMES_KERN3[..], TIMES_METRICS[3], None, TIMES[3]);
     [a, b, c, d]
 }
 
 /// Courier standard font family
 pub fn courier() -> FontFamily {
-    let a = StandardFont::make(&COURIER0[..], COURIER[0]);
-    let b = StandardFont::make(&COURIER1[..], COURIER[1]);
-    let c = StandardFont::make(&COURIER2[..], COURIER[2]);
-    let d = StandardFont::make(&COURIER3[..], COURIER[3]);
+    // Courier is fixed pitch, so has no kerning.
+    let a = StandardFont::make(&COURIER0[..], &[], COURIER_METRICS[0], None, COURIER[0]);
+    let b = StandardFont::make(&COURIER1[..], &[], COURIER_METRICS[1], None, COURIER[1]);
+    let c = StandardFont::make(&COURIER2[..], &[], COURIER_METRICS[2], None, COURIER[2]);
+    let d = StandardFont::make(&COURIER3[..], &[], COURIER_METRICS[3], None, COURIER[3]);
     [a, b, c, d]
 }
 
+/// Symbol standard font ( Greek letters, mathematical operators and arrows ), uses its built-in encoding.
+pub fn symbol() -> Box<dyn Font> {
+    StandardFont::make(&SYMBOL[..], &[], SYMBOL_METRICS, Some(&SYMBOL_MAP[..]), "Symbol")
+}
+
+/// ZapfDingbats standard font ( e.g. check marks, crosses, telephones, arrows ), uses its built-in encoding.
+pub fn zapf_dingbats() -> Box<dyn Font> {
+    let map = Some(&ZAPF_DINGBATS_MAP[..]);
+    StandardFont::make(&ZAPF_DINGBATS[..], &[], ZAPF_DINGBATS_METRICS, map, "ZapfDingbats")
+}
+
 /// Standard Font
 #[derive(Default)]
 pub struct StandardFont {
     obj: usize,
     size_data: &'static [u16],
+    kern_data: &'static [(char, char, i16)],
+    metrics: FontMetrics,
+    /// Unicode to code map for a font with a built-in encoding ( None means WinAnsiEncoding ).
+    builtin: Option<&'static [(char, u8)]>,
     name: &'static str,
 }
 
 impl StandardFont {
-    fn make(size_data: &'static [u16], name: &'static str) -> Box<dyn Font> {
+    fn make(
+        size_data: &'static [u16],
+        kern_data: &'static [(char, char, i16)],
+        metrics: FontMetrics,
+        builtin: Option<&'static [(char, u8)]>,
+        name: &'static str,
+    ) -> Box<dyn Font> {
         Box::new(Self {
             obj: 0,
             size_data,
+            kern_data,
+            metrics,
+            builtin,
             name,
         })
     }
+
+    /// Code for c in the built-in encoding.
+    fn code(map: &[(char, u8)], c: char) -> Option<u8> {
+        map.binary_search_by_key(&c, |x| x.0).ok().map(|i| map[i].1)
+    }
 }
 
 impl Font for StandardFont {
@@ -72,34 +187,81 @@ impl Font for StandardFont {
     }
 
     fn width(&self, c: char) -> MPx {
-        let mut c = c as usize;
+        let mut c = match self.builtin {
+            Some(map) => Self::code(map, c).unwrap_or(32) as usize,
+            None => c as usize,
+        };
         if c < 32 || c - 32 >= self.size_data.len() {
             c = 32;
         }
         self.size_data[c - 32] as MPx
     }
 
+    fn metrics(&self) -> FontMetrics {
+        self.metrics
+    }
+
+    fn kern(&self, a: char, b: char) -> MPx {
+        match self.kern_data.binary_search_by(|x| (x.0, x.1).cmp(&(a, b))) {
+            Ok(i) => self.kern_data[i].2 as MPx,
+            Err(_) => 0,
+        }
+    }
+
     fn init(&mut self, w: &mut BasicPdfWriter) {
         if self.obj == 0 {
-            self.obj = w.begin();
-            let _ = wb!(
-                &mut w.b,
-                b"<</Type/Font/Subtype/Type1/Name/F{}/BaseFont/{}/Encoding/WinAnsiEncoding>>",
-                self.obj,
-                self.name.as_bytes()
-            );
-            w.end();
+            w.violation(format!("font {} is not embedded", self.name));
+            self.obj = w.obj();
+            let mut d = Dict::new()
+                .with("Type", Object::name("Font"))
+                .with("Subtype", Object::name("Type1"))
+                .with("Name", Object::name(&format!("F{}", self.obj)))
+                .with("BaseFont", Object::name(self.name));
+            if self.builtin.is_none() {
+                d.set("Encoding", Object::name("WinAnsiEncoding"));
+            }
+            w.write_obj(self.obj, &Object::Dict(d));
         }
     }
 
-    fn encode(&self, s: &str, to: &mut Vec<u8>) {
+    fn encode(&self, s: &str, to: &mut Vec<u8>) -> Result<()> {
+        let mut result = Ok(());
+        if let Some(map) = self.builtin {
+            // Characters that cannot be encoded are replaced by a space ( '?' may be a symbol ).
+            for c in s.chars() {
+                match Self::code(map, c) {
+                    Some(x) => to.push(x),
+                    None => {
+                        to.push(b' ');
+                        if result.is_ok() {
+                            result = Err(Error::Encoding(self.name.to_string(), c));
+                        }
+                    }
+                }
+            }
+            return result;
+        }
         let mut e = encoding_rs::WINDOWS_1252.new_encoder();
-        let x = e
-            .max_buffer_length_from_utf8_without_replacement(s.len())
-            .unwrap();
-        to.reserve(x); // Weird that this is necessary.
-        let (r, _n) = e.encode_from_utf8_to_vec_without_replacement(s, to, false);
-        assert!(r == encoding_rs::EncoderResult::InputEmpty);
+        let mut s = s;
+        loop {
+            // Output is written to spare capacity only.
+            let x = e
+                .max_buffer_length_from_utf8_without_replacement(s.len())
+                .unwrap_or(s.len() * 3);
+            to.reserve(x);
+            let (r, n) = e.encode_from_utf8_to_vec_without_replacement(s, to, false);
+            s = &s[n..];
+            match r {
+                encoding_rs::EncoderResult::InputEmpty => return result,
+                encoding_rs::EncoderResult::OutputFull => {}
+                encoding_rs::EncoderResult::Unmappable(c) => {
+                    to.push(b'?');
+                    if result.is_ok() {
+                        result = Err(Error::Encoding(self.name.to_string(), c));
+                    }
+                }
+            }
+        }
     }
 }
 
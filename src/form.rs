@@ -0,0 +1,342 @@
+//!# Form example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    let mut w = Writer::default();
+//!    html(&mut w, b"<p>Name <input type=text name=name size=30>
+//!<p><input type=checkbox name=agree checked> I agree
+//!<p><input type=radio name=size value=S> Small <input type=radio name=size value=L checked> Large
+//!<p><textarea name=notes rows=3 cols=40>First line
+//!Second line</textarea>
+//!<p><select name=colour><option>Red<option selected>Green</select>").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(9).any(|x| x == b"/AcroForm"));
+//!
+//!    // A field may be the first item on a page ( the font is set before the space is left ).
+//!    let mut w = Writer::default();
+//!    w.b.nocomp = true;
+//!    html(&mut w, b"<input type=text name=a> hello").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(26).any(|x| x == b"/F2 10 Tf [-10000] TJ ( he"));
+//! ```
+
+use crate::*;
+
+/// Interactive form field.
+#[derive(Clone, Debug)]
+pub struct Field {
+    /// Field name ( for radio buttons, the name of the group ).
+    pub name: String,
+    /// Tool tip ( also used as the field description by screen readers ).
+    pub tooltip: String,
+    /// Font size of field text.
+    pub font_size: Px,
+    /// Field type and value.
+    pub kind: FieldKind,
+}
+
+/// Field type and value.
+#[derive(Clone, Debug)]
+pub enum FieldKind {
+    /// Text field, value, multiline.
+    Text(String, bool),
+    /// Check box, export value ( e.g. "Yes" ), checked.
+    CheckBox(String, bool),
+    /// Radio button, export value, checked ( buttons with the same name form a group ).
+    Radio(String, bool),
+    /// Combo box ( drop-down ), options ( export value, text ), index of selected option.
+    Combo(Vec<(String, String)>, Option<usize>),
+    /// List box, options ( export value, text ), index of selected option.
+    List(Vec<(String, String)>, Option<usize>),
+    /// Signature field ( signed by finish if b.signature is set ), invisible if the size is zero.
+    Signature,
+}
+
+/// Radio button group ( parent field of the buttons ).
+#[derive(Clone, Debug, Default)]
+pub struct RadioGroup {
+    /// PDF obj number of parent field.
+    pub obj: usize,
+    /// Obj numbers of button widgets.
+    pub kids: Vec<usize>,
+    /// Export value of checked button.
+    pub value: Option<String>,
+}
+
+/// Encode s as WinAnsi literal string ( for use in appearance streams ).
+fn win_ansi(s: &str) -> Object {
+    let (e, _, _) = encoding_rs::WINDOWS_1252.encode(s);
+    Object::String(e.into_owned())
+}
+
+/// Append operations to clip to the inside of a field of size w x h and begin text in Helvetica
+/// ( size fs, leading lh ) at (2,y).
+fn begin_text(on: &mut Vec<u8>, w: f32, h: f32, fs: Px, lh: f32, y: f32) {
+    op(&[1.into(), 1.into(), (w - 2.0).into(), (h - 2.0).into()], "re", on);
+    op(&[], "W", on);
+    op(&[], "n", on);
+    op(&[], "BT", on);
+    op(&[Object::name("Helv"), fs.into()], "Tf", on);
+    op(&[0.into()], "g", on);
+    op(&[lh.into()], "TL", on);
+    op(&[2.into(), y.into()], "Td", on);
+}
+
+/// Append lines of text shown with Tj ( a line per T* ).
+fn show_lines<'a>(lines: impl Iterator<Item = &'a str>, on: &mut Vec<u8>) {
+    for (i, line) in lines.enumerate() {
+        if i > 0 {
+            op(&[], "T*", on);
+        }
+        op(&[win_ansi(line)], "Tj", on);
+    }
+}
+
+/// Font resources of form fields.
+fn form_font_dict(helv: usize, zadb: usize) -> Dict {
+    Dict::new()
+        .with("Helv", Object::Ref(helv))
+        .with("ZaDb", Object::Ref(zadb))
+}
+
+impl BasicPdfWriter {
+    /// Obj numbers of fonts used by form fields ( Helvetica and ZapfDingbats ), written when first needed.
+    pub fn form_fonts(&mut self) -> (usize, usize) {
+        if self.form_fonts.0 == 0 {
+            self.violation("form font Helvetica is not embedded".to_string());
+            self.violation("form font ZapfDingbats is not embedded".to_string());
+            let font = |name| {
+                Dict::new()
+                    .with("Type", Object::name("Font"))
+                    .with("Subtype", Object::name("Type1"))
+                    .with("BaseFont", Object::name(name))
+            };
+            let helv = font("Helvetica").with("Encoding", Object::name("WinAnsiEncoding"));
+            let helv = self.add_obj(&Object::Dict(helv));
+            let zadb = self.add_obj(&Object::Dict(font("ZapfDingbats")));
+            self.form_fonts = (helv, zadb);
+        }
+        self.form_fonts
+    }
+
+    /// Write appearance stream ( form XObject ) of size w x h, result is obj number.
+    fn appearance(&mut self, w: f32, h: f32, content: &[u8]) -> usize {
+        let (helv, zadb) = self.form_fonts();
+        let obj = self.obj();
+        let dict = Dict::new()
+            .with("Type", Object::name("XObject"))
+            .with("Subtype", Object::name("Form"))
+            .with("BBox", Object::nums(&[0.0, 0.0, w, h]))
+            .with("Resources", Dict::new().with("Font", form_font_dict(helv, zadb)));
+        self.stream_obj(obj, &dict, content);
+        obj
+    }
+
+    /// Write form field widget annotation covering rect on page, result is obj number ( to be added to Page::annots ).
+    /// elem is the Form structure element ( tagged PDF ).
+    pub fn field(&mut self, f: &Field, rect: [f32; 4], page: usize, elem: Option<usize>) -> usize {
+        let (w, h) = (rect[2] - rect[0], rect[3] - rect[1]);
+        let fs = f.font_size;
+        let mut border = Vec::new();
+        op(&[0.5.into()], "w", &mut border);
+        op(&[0.into()], "G", &mut border);
+        op(&[0.25.into(), 0.25.into(), (w - 0.5).into(), (h - 0.5).into()], "re", &mut border);
+        op(&[], "S", &mut border);
+
+        // Appearance streams ( normal, and off state for buttons ).
+        let mut on = border.clone();
+        let mut state = None;
+        match &f.kind {
+            FieldKind::Text(value, multiline) => {
+                let top = if *multiline {
+                    h - 2.0 - fs as f32
+                } else {
+                    (h - fs as f32 * 0.7) / 2.0
+                };
+                op(&[Object::name("Tx")], "BMC", &mut on);
+                op(&[], "q", &mut on);
+                begin_text(&mut on, w, h, fs, (fs + 1) as f32, top);
+                show_lines(value.lines(), &mut on);
+                on.extend_from_slice(b" ET Q EMC");
+            }
+            FieldKind::CheckBox(_, _) | FieldKind::Radio(_, _) => {
+                let (c, gw) = if let FieldKind::Radio(..) = f.kind {
+                    (b'l', 0.791)
+                } else {
+                    (b'4', 0.846)
+                };
+                let size = h * 0.8;
+                op(&[], "q", &mut on);
+                op(&[], "BT", &mut on);
+                op(&[Object::name("ZaDb"), size.into()], "Tf", &mut on);
+                op(&[0.into()], "g", &mut on);
+                let (x, y) = ((w - size * gw) / 2.0, (h - size * 0.7) / 2.0);
+                op(&[x.into(), y.into()], "Td", &mut on);
+                op(&[Object::String(vec![c])], "Tj", &mut on);
+                on.extend_from_slice(b" ET Q");
+            }
+            FieldKind::Combo(opts, sel) => {
+                op(&[Object::name("Tx")], "BMC", &mut on);
+                op(&[], "q", &mut on);
+                let y = (h - fs as f32 * 0.7) / 2.0;
+                begin_text(&mut on, w, h, fs, (fs + 1) as f32, y);
+                if let Some((_, text)) = sel.and_then(|i| opts.get(i)) {
+                    op(&[win_ansi(text)], "Tj", &mut on);
+                }
+                on.extend_from_slice(b" ET Q EMC");
+            }
+            FieldKind::List(opts, sel) => {
+                let lh = (fs + 1) as f32;
+                op(&[Object::name("Tx")], "BMC", &mut on);
+                op(&[], "q", &mut on);
+                if let Some(i) = sel {
+                    // Highlight the selected option.
+                    op(&[0.6.into(), 0.75.into(), 0.85.into()], "rg", &mut on);
+                    let y = h - 1.0 - lh * (*i + 1) as f32;
+                    op(&[1.into(), y.into(), (w - 2.0).into(), lh.into()], "re", &mut on);
+                    op(&[], "f", &mut on);
+                }
+                let y = h - 1.0 - lh + 0.25 * fs as f32;
+                begin_text(&mut on, w, h, fs, lh, y);
+                show_lines(opts.iter().map(|(_, text)| text.as_str()), &mut on);
+                on.extend_from_slice(b" ET Q EMC");
+            }
+            FieldKind::Signature => {
+                on.clear();
+                if w > 0.0 && h > 0.0 {
+                    on.extend_from_slice(&border);
+                    op(&[], "q", &mut on);
+                    begin_text(&mut on, w, h, fs, (fs + 1) as f32, h - 1.0 - fs as f32);
+                    show_lines(self.sig_text().iter().map(|s| s.as_str()), &mut on);
+                    on.extend_from_slice(b" ET Q");
+                }
+            }
+        }
+        let ap_on = self.appearance(w, h, &on);
+        if let FieldKind::CheckBox(v, c) | FieldKind::Radio(v, c) = &f.kind {
+            let off = self.appearance(w, h, &border);
+            state = Some((v.clone(), *c, off));
+        }
+
+        let group = if let FieldKind::Radio(v, c) = &f.kind {
+            if !self.radio_groups.contains_key(&f.name) {
+                let obj = self.obj();
+                self.fields.push(obj);
+                let g = RadioGroup {
+                    obj,
+                    ..Default::default()
+                };
+                self.radio_groups.insert(f.name.clone(), g);
+            }
+            let g = self.radio_groups.get_mut(&f.name).unwrap();
+            if *c {
+                g.value = Some(v.clone());
+            }
+            Some(g.obj)
+        } else {
+            None
+        };
+
+        let obj = self.obj();
+        let mut mk = Dict::new().with("BC", Object::nums(&[0.0, 0.0, 0.0]));
+        if state.is_some() {
+            let ca = if group.is_some() { "l" } else { "4" };
+            mk.set("CA", Object::text(ca));
+        }
+        let mut d = Dict::new()
+            .with("Type", Object::name("Annot"))
+            .with("Subtype", Object::name("Widget"))
+            .with("Rect", Object::nums(&rect))
+            .with("F", 4)
+            .with("P", Object::Ref(page))
+            .with("MK", mk);
+        if let Some(g) = group {
+            d.set("Parent", Object::Ref(g));
+            self.radio_groups.get_mut(&f.name).unwrap().kids.push(obj);
+        } else {
+            self.fields.push(obj);
+            d.set("T", Object::text(&f.name));
+            if !f.tooltip.is_empty() {
+                d.set("TU", Object::text(&f.tooltip));
+            }
+        }
+        d.set("DA", Object::text(&format!("/Helv {} Tf 0 g", fs)));
+        match &f.kind {
+            FieldKind::Text(value, multiline) => {
+                d.set("FT", Object::name("Tx"));
+                d.set("V", Object::text(value));
+                if *multiline {
+                    d.set("Ff", 4096);
+                }
+            }
+            FieldKind::CheckBox(v, c) => {
+                d.set("FT", Object::name("Btn"));
+                d.set("V", Object::name(if *c { v } else { "Off" }));
+            }
+            FieldKind::Radio(..) => {}
+            FieldKind::Signature => {
+                d.set("FT", Object::name("Sig"));
+                if self.signature.is_some() {
+                    d.set("V", Object::Ref(self.sig_obj()));
+                } else {
+                    self.sig_field_unlinked = true;
+                }
+            }
+            FieldKind::Combo(opts, sel) | FieldKind::List(opts, sel) => {
+                d.set("FT", Object::name("Ch"));
+                if matches!(f.kind, FieldKind::Combo(..)) {
+                    d.set("Ff", 131072);
+                }
+                let opt: Vec<Object> = opts
+                    .iter()
+                    .map(|(v, t)| Object::Array(vec![Object::text(v), Object::text(t)]))
+                    .collect();
+                d.set("Opt", opt);
+                if let Some((v, _)) = sel.and_then(|i| opts.get(i)) {
+                    d.set("V", Object::text(v));
+                }
+            }
+        }
+        if let Some((v, c, off)) = state {
+            d.set("AS", Object::name(if c { &v } else { "Off" }));
+            let n = Dict::new()
+                .with(&v, Object::Ref(ap_on))
+                .with("Off", Object::Ref(off));
+            d.set("AP", Dict::new().with("N", n));
+        } else {
+            d.set("AP", Dict::new().with("N", Object::Ref(ap_on)));
+        }
+        if let Some(e) = elem {
+            d.set("StructParent", self.annot_struct_parent(e, obj));
+        }
+        self.write_obj(obj, &Object::Dict(d));
+        obj
+    }
+
+    /// Write radio group fields and the interactive form dictionary, result is obj number ( zero if there are no fields ).
+    pub fn acroform(&mut self) -> usize {
+        if self.fields.is_empty() {
+            return 0;
+        }
+        let groups = std::mem::take(&mut self.radio_groups);
+        for (name, g) in &groups {
+            let d = Dict::new()
+                .with("FT", Object::name("Btn"))
+                .with("Ff", 49152)
+                .with("T", Object::text(name))
+                .with("V", Object::name(g.value.as_deref().unwrap_or("Off")))
+                .with("Kids", Object::refs(&g.kids));
+            self.write_obj(g.obj, &Object::Dict(d));
+        }
+        let (helv, zadb) = self.form_fonts();
+        let fields = std::mem::take(&mut self.fields);
+        let mut d = Dict::new()
+            .with("Fields", Object::refs(&fields))
+            .with("DA", Object::text("/Helv 0 Tf 0 g"))
+            .with("DR", Dict::new().with("Font", form_font_dict(helv, zadb)));
+        if self.sig_obj != 0 {
+            d.set("SigFlags", 3);
+        }
+        self.add_obj(&Object::Dict(d))
+    }
+}
@@ -2,10 +2,12 @@ use crate::*;
 use std::collections::BTreeMap;
 
 /// Convert source html to PDF using Writer w.
-pub fn html(w: &mut Writer, source: &[u8]) {
+/// Error if source is not UTF-8, text cannot be encoded or an image cannot be fetched.
+pub fn html(w: &mut Writer, source: &[u8]) -> Result<()> {
+    std::str::from_utf8(source).map_err(|e| Error::Utf8(e.valid_up_to()))?;
     let mut p = Parser::new(source);
     p.read_token();
-    html_inner(w, &mut p, b"");
+    html_inner(w, &mut p, b"")
 }
 
 #[derive(Debug)]
@@ -85,15 +87,20 @@ impl<'a> Parser<'a> {
             while c != b'=' && c != b' ' && c != b'>' && c != 0 {
                 c = self.next();
             }
-            if c == b'>' {
-                return;
-            }
             let attr_name = &self.source[attr_name_start..self.position - 1];
             if c == b' ' {
                 c = self.next_non_space();
             }
             if c != b'=' {
-                return;
+                // Attribute without value, e.g. checked
+                if !attr_name.is_empty() {
+                    self.attr.insert(attr_name, b"");
+                }
+                if c == b'>' || c == 0 {
+                    return;
+                }
+                self.position -= 1;
+                continue;
             }
             c = self.next_non_space();
             let start = self.position - 1;
@@ -121,6 +128,17 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Read raw text up to end tag ( e.g. for textarea ), the end tag is the next token.
+    fn read_raw(&mut self, end: &[u8]) -> &'a [u8] {
+        let start = self.position;
+        let rest = &self.source[start..];
+        let n = (0..rest.len())
+            .find(|&i| rest[i..].starts_with(b"</") && rest[i + 2..].starts_with(end))
+            .unwrap_or(rest.len());
+        self.position += n;
+        &rest[..n]
+    }
+
     fn read_token(&mut self) {
         let c = self.next();
         if c == 0 {
@@ -140,6 +158,7 @@ impl<'a> Parser<'a> {
             // e.g. <h1 name=x> or </h1>
             self.token = Token::Tag;
             self.token_start = self.position;
+            self.attr.clear();
             self.end_tag = false;
             let mut c = self.next();
             if c == b'/' {
@@ -182,20 +201,20 @@ impl<'a> Parser<'a> {
     }
 }
 
-fn html_inner(w: &mut Writer, p: &mut Parser, endtag: &[u8]) {
+fn html_inner(w: &mut Writer, p: &mut Parser, endtag: &[u8]) -> Result<()> {
     loop {
         match p.token {
             Token::Eof => {
-                return;
+                return Ok(());
             }
             Token::WhiteSpace => {
-                w.space();
+                w.space()?;
                 p.read_token();
             }
             Token::Text => {
                 let s = tos(p.tvalue());
                 let s = &html_escape::decode_html_entities(s);
-                w.text(s);
+                w.text(s)?;
                 p.read_token();
             }
             Token::Tag => {
@@ -204,20 +223,64 @@ fn html_inner(w: &mut Writer, p: &mut Parser, endtag: &[u8]) {
                     if tag == endtag {
                         p.read_token();
                     }
-                    return;
+                    return Ok(());
                 } else if tag == b"p" && tag == endtag {
-                    return;
+                    return Ok(());
                 }
-                p.read_token();
                 if tag == b"br" || tag == b"br/" {
+                    p.read_token();
                     w.output_line();
                 } else if tag == b"img" {
                     if let Some(src) = p.avalue(b"src") {
                         let width = p.aint(b"width");
                         let height = p.aint(b"height");
-                        w.image(tos(src), width, height);
+                        let alt = p
+                            .avalue(b"alt")
+                            .map(|a| html_escape::decode_html_entities(tos(a)).to_string());
+                        let save = w.tag_start("Figure", alt);
+                        let r = w.image(tos(src), width, height);
+                        w.tag_end(save);
+                        r?;
                     }
+                    p.read_token();
+                } else if tag == b"input" {
+                    input(w, p);
+                    p.read_token();
+                } else if tag == b"textarea" {
+                    let name = attr(p, b"name");
+                    let title = attr(p, b"title");
+                    let rows = p.aint(b"rows").unwrap_or(2);
+                    let cols = p.aint(b"cols").unwrap_or(20);
+                    let value = tos(p.read_raw(b"textarea"));
+                    let value = html_escape::decode_html_entities(value);
+                    let kind = FieldKind::Text(value.trim_start_matches('\n').to_string(), true);
+                    let (width, height) = (cols * w.font_size / 2, rows * (w.font_size + 1) + 4);
+                    w.field(kind, &name, &title, width, height);
+                    p.read_token();
+                    p.read_token();
+                } else if tag == b"select" {
+                    select(w, p);
+                } else if tag == b"style" {
+                    page_rules(w, tos(p.read_raw(b"style")));
+                    p.read_token();
+                    p.read_token();
+                } else if tag == b"meta" {
+                    meta(w, p);
+                    p.read_token();
                 } else {
+                    let href = p.avalue(b"href").map(|h| tos(h));
+                    let mut id = p.avalue(b"id");
+                    if tag == b"a" {
+                        id = id.or(p.avalue(b"name"));
+                    }
+                    let id = id.map(|x| tos(x));
+                    let lang = p.avalue(b"lang").map(|x| tos(x));
+                    let style = p.avalue(b"style").map(|x| tos(x));
+                    p.read_token();
+                    let (restore_page, break_after) = match style {
+                        Some(style) => page_style(w, style),
+                        None => (None, false),
+                    };
                     let save_mode = w.mode;
                     let save_font = w.cur_font;
                     let save_font_size = w.font_size;
@@ -230,10 +293,21 @@ fn html_inner(w: &mut Writer, p: &mut Parser, endtag: &[u8]) {
                             save = if w.center { 1 } else { 0 };
                             w.center = true;
                         }
+                        b"h2" | b"h3" | b"h4" | b"h5" | b"h6" => {
+                            w.font_size = [13, 12, 11, 10, 10][(tag[1] - b'2') as usize];
+                            w.cur_font |= 1;
+                            w.output_line();
+                        }
                         b"b" => w.cur_font |= 1,
                         b"i" => w.cur_font |= 2,
                         b"title" => w.mode = Mode::Title,
-                        b"html" | b"head" => w.mode = Mode::Head,
+                        b"html" => {
+                            w.mode = Mode::Head;
+                            if let Some(lang) = lang {
+                                w.info.lang = lang.to_string();
+                            }
+                        }
+                        b"head" => w.mode = Mode::Head,
                         b"body" => w.mode = Mode::Normal,
                         b"sup" => {
                             save = w.sup;
@@ -245,7 +319,21 @@ fn html_inner(w: &mut Writer, p: &mut Parser, endtag: &[u8]) {
                         }
                         _ => {}
                     }
-                    html_inner(w, p, tag);
+                    let save_tag = struct_kind(tag).map(|kind| w.tag_start(kind, None));
+                    if tag == b"a"
+                        && let Some(href) = href
+                    {
+                        w.link_start(&html_escape::decode_html_entities(href));
+                        save = 1;
+                    }
+                    if let Some(id) = id {
+                        w.dest(&html_escape::decode_html_entities(id));
+                    }
+                    let heading = heading_level(tag).map(|level| {
+                        let save_capture = w.capture.replace(String::new());
+                        (w.bookmark("", level), save_capture)
+                    });
+                    html_inner(w, p, tag)?;
                     w.mode = save_mode;
                     w.font_size = save_font_size;
                     w.cur_font = save_font;
@@ -255,15 +343,265 @@ fn html_inner(w: &mut Writer, p: &mut Parser, endtag: &[u8]) {
                             w.output_line();
                             w.center = save == 1;
                         }
+                        b"h2" | b"h3" | b"h4" | b"h5" | b"h6" => w.output_line(),
+                        b"a" if save == 1 => w.link_end(),
                         _ => {}
                     }
+                    if let Some(save_tag) = save_tag {
+                        w.tag_end(save_tag);
+                    }
+                    if let Some((i, save_capture)) = heading {
+                        let title = std::mem::replace(&mut w.capture, save_capture);
+                        w.b.outline[i].title = title.unwrap_or_default().trim().to_string();
+                    }
+                    if let Some(s) = restore_page {
+                        w.page_break();
+                        w.set_page(s);
+                    }
+                    if break_after {
+                        w.page_break();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Heading level for h1 to h6.
+fn heading_level(tag: &[u8]) -> Option<usize> {
+    match tag {
+        [b'h', c @ b'1'..=b'6'] => Some((c - b'0') as usize),
+        _ => None,
+    }
+}
+
+/// Decoded attribute value ( empty if not present ).
+fn attr(p: &Parser, name: &[u8]) -> String {
+    p.avalue(name)
+        .map(|v| html_escape::decode_html_entities(tos(v)).to_string())
+        .unwrap_or_default()
+}
+
+/// Form field from input tag, e.g. &lt;input type=checkbox name=agree checked&gt;
+fn input(w: &mut Writer, p: &Parser) {
+    let name = attr(p, b"name");
+    let title = attr(p, b"title");
+    let value = attr(p, b"value");
+    let checked = p.avalue(b"checked").is_some();
+    let fs = w.font_size;
+    let (kind, width) = match p.avalue(b"type").map(|t| t.to_ascii_lowercase()).as_deref() {
+        None | Some(b"text") => {
+            let width = p.aint(b"size").unwrap_or(20) * fs / 2;
+            (FieldKind::Text(value, false), width)
+        }
+        Some(b"checkbox") => {
+            let value = if value.is_empty() { "Yes".to_string() } else { value };
+            (FieldKind::CheckBox(value, checked), fs + 2)
+        }
+        Some(b"radio") => (FieldKind::Radio(value, checked), fs + 2),
+        _ => return,
+    };
+    let width = p.aint(b"width").unwrap_or(width);
+    w.field(kind, &name, &title, width, fs + 4);
+}
+
+/// Form field from select tag and its options, reads up to the end tag.
+fn select(w: &mut Writer, p: &mut Parser) {
+    let name = attr(p, b"name");
+    let title = attr(p, b"title");
+    let size = p.aint(b"size").unwrap_or(1);
+    let width = p.aint(b"width");
+    // Export value ( if given ) and text of each option.
+    let mut options: Vec<(Option<String>, String)> = Vec::new();
+    let mut selected = None;
+    p.read_token();
+    loop {
+        match p.token {
+            Token::Eof => break,
+            Token::Tag if p.end_tag && p.tvalue() == b"select" => {
+                p.read_token();
+                break;
+            }
+            Token::Tag if !p.end_tag && p.tvalue() == b"option" => {
+                if p.avalue(b"selected").is_some() {
+                    selected = Some(options.len());
+                }
+                let value = p.avalue(b"value").map(|_| attr(p, b"value"));
+                options.push((value, String::new()));
+            }
+            Token::Text => {
+                if let Some(o) = options.last_mut() {
+                    o.1 += &html_escape::decode_html_entities(tos(p.tvalue()));
+                }
+            }
+            Token::WhiteSpace => {
+                if let Some(o) = options.last_mut() {
+                    o.1.push(' ');
+                }
+            }
+            _ => {}
+        }
+        p.read_token();
+    }
+    let options: Vec<(String, String)> = options
+        .into_iter()
+        .map(|(v, t)| {
+            let t = t.trim().to_string();
+            (v.unwrap_or_else(|| t.clone()), t)
+        })
+        .collect();
+    let fs = w.font_size;
+    let chars = options.iter().map(|o| o.1.chars().count()).max().unwrap_or(0) as Px;
+    let width = width.unwrap_or((chars + 4) * fs / 2);
+    if size > 1 {
+        let height = size * (fs + 1) + 4;
+        w.field(FieldKind::List(options, selected), &name, &title, width, height);
+    } else {
+        w.field(FieldKind::Combo(options, selected), &name, &title, width, fs + 4);
+    }
+}
+
+/// Set document information from meta tag, e.g. &lt;meta name=author content="J. Smith"&gt;
+fn meta(w: &mut Writer, p: &Parser) {
+    if let (Some(name), Some(content)) = (p.avalue(b"name"), p.avalue(b"content")) {
+        let content = html_escape::decode_html_entities(tos(content)).to_string();
+        match name.to_ascii_lowercase().as_slice() {
+            b"author" => w.info.author = content,
+            b"description" => w.info.subject = content,
+            b"keywords" => w.info.keywords = content,
+            _ => {}
+        }
+    }
+}
+
+/// CSS declarations as ( lower case name, value ) pairs, e.g. "size: A4; margin: 2cm".
+fn declarations(css: &str) -> Vec<(String, &str)> {
+    css.split(';')
+        .filter_map(|d| d.split_once(':'))
+        .map(|(n, v)| (n.trim().to_ascii_lowercase(), v.trim()))
+        .collect()
+}
+
+/// CSS length in points, e.g. "2cm", "20mm", "1in", "12pt" ( px and plain numbers are taken as points ).
+fn css_length(s: &str) -> Option<Px> {
+    let s = s.to_ascii_lowercase();
+    let (num, scale) = [
+        ("mm", 72.0 / 25.4),
+        ("cm", 72.0 / 2.54),
+        ("in", 72.0),
+        ("pt", 1.0),
+        ("px", 1.0),
+    ]
+    .into_iter()
+    .find_map(|(unit, scale)| s.strip_suffix(unit).map(|n| (n, scale)))
+    .unwrap_or((&s, 1.0));
+    num.parse::<f32>().ok().map(|x| (x * scale).round() as Px)
+}
+
+/// Page size from CSS size value, e.g. "A4 landscape", "landscape" or "842pt 595pt". base is the current size.
+fn css_size(v: &str, base: (Px, Px)) -> Option<(Px, Px)> {
+    let mut size = None;
+    let mut o = None;
+    let mut lengths = Vec::new();
+    for t in v.split_whitespace() {
+        if let Some(p) = PaperSize::from_name(t) {
+            size = Some(p.size(Orientation::Portrait));
+        } else if t.eq_ignore_ascii_case("portrait") {
+            o = Some(Orientation::Portrait);
+        } else if t.eq_ignore_ascii_case("landscape") {
+            o = Some(Orientation::Landscape);
+        } else {
+            lengths.push(css_length(t)?);
+        }
+    }
+    let (w, h) = match lengths[..] {
+        [x] => (x, x),
+        [x, y] => (x, y),
+        [] => size.unwrap_or(base),
+        _ => return None,
+    };
+    Some(match o {
+        Some(Orientation::Portrait) => (w.min(h), w.max(h)),
+        Some(Orientation::Landscape) => (w.max(h), w.min(h)),
+        None => (w, h),
+    })
+}
+
+/// Margins ( top, right, bottom, left ) from CSS margin value with one to four lengths.
+fn css_margin(v: &str) -> Option<[Px; 4]> {
+    let m: Option<Vec<Px>> = v.split_whitespace().map(css_length).collect();
+    match m?[..] {
+        [a] => Some([a, a, a, a]),
+        [a, b] => Some([a, b, a, b]),
+        [a, b, c] => Some([a, b, c, b]),
+        [a, b, c, d] => Some([a, b, c, d]),
+        _ => None,
+    }
+}
+
+/// Page setups from @page rules in a style element, e.g. @page { size: A4 landscape; margin: 2cm }
+/// Named rules ( e.g. @page wide { ... } ) are used by elements with style="page: wide".
+fn page_rules(w: &mut Writer, css: &str) {
+    let mut rest = css;
+    while let Some(i) = rest.find("@page") {
+        rest = &rest[i + 5..];
+        let (Some(open), Some(close)) = (rest.find('{'), rest.find('}')) else {
+            return;
+        };
+        if close < open {
+            continue;
+        }
+        let name = rest[..open].trim();
+        let body = &rest[open + 1..close];
+        rest = &rest[close + 1..];
+        // Page selectors such as :first are not supported.
+        if name.contains(':') {
+            continue;
+        }
+        let mut s = w.page_setup();
+        for (n, v) in declarations(body) {
+            match n.as_str() {
+                "size" => {
+                    if let Some((width, height)) = css_size(v, (s.width, s.height)) {
+                        s.width = width;
+                        s.height = height;
+                    }
+                }
+                "margin" => s.margins = css_margin(v).or(s.margins),
+                _ => {}
+            }
+        }
+        if name.is_empty() {
+            w.set_page(s);
+        } else {
+            w.named_pages.insert(name.to_string(), s);
+        }
+    }
+}
+
+/// Page breaks and named page from style attribute, e.g. style="page: wide" or style="break-before: page".
+/// Result is the page setup to restore at the end of the element ( for a named page ) and whether to break after it.
+fn page_style(w: &mut Writer, style: &str) -> (Option<PageSetup>, bool) {
+    let mut restore = None;
+    let mut after = false;
+    for (n, v) in declarations(style) {
+        match (n.as_str(), v) {
+            ("page-break-before", "always") | ("break-before", "page") => w.page_break(),
+            ("page-break-after", "always") | ("break-after", "page") => after = true,
+            ("page", name) => {
+                if let Some(s) = w.named_pages.get(name).copied() {
+                    restore = Some(w.page_setup());
+                    w.page_break();
+                    w.set_page(s);
                 }
             }
+            _ => {}
         }
     }
+    (restore, after)
 }
 
-/// Convert byte slice into string.
+/// Convert byte slice into string ( html checks the source is UTF-8, and tokens start and end at ASCII characters ).
 fn tos(s: &[u8]) -> &str {
-    std::str::from_utf8(s).unwrap()
+    std::str::from_utf8(s).unwrap_or_default()
 }
@@ -1,10 +1,10 @@
 //!# Test example
 //!
 //! ```
-//!      use pdf_min::{Writer, html, writer::Fetcher, image::{ImageSpec,Image}};
+//!      use pdf_min::{Writer, html, writer::Fetcher, image::{ImageSpec,Image}, error::Result, object::{Dict, Object}};
 //!      struct MyFetcher;
 //!      impl Fetcher for MyFetcher {
-//!         fn image(&mut self, w: &mut Writer, _name: &str) -> Image {         
+//!         fn image(&mut self, w: &mut Writer, _name: &str) -> Result<Image> {
 //!             let mut data = Vec::new();
 //!             for i in 0..3 * 16 * 16 {
 //!                 data.push( i as u8 );  // Red
@@ -12,8 +12,8 @@
 //!                 data.push( (i + 170 ) as u8 ); // Blue
 //!             }
 //!             let ims = ImageSpec{ data: &data, width:16, height:16,
-//!                 bits_per_component:8, color_space: b"/DeviceRGB", other: b"" };
-//!             Image::new( &ims, &mut w.b )
+//!                 bits_per_component:8, color_space: Object::name("DeviceRGB"), other: Dict::new() };
+//!             Ok(Image::new( &ims, &mut w.b ))
 //!         }
 //!      }
 //!      let mut w = Writer::default();
@@ -22,8 +22,8 @@
 //!      w.fetcher = Some(Box::new(MyFetcher));
 //!   
 //!      // Draw text with image
-//!      html( &mut w, b"<p><b>Bold Text Before Image</b> <img width=32 src=myimg> Text after image" );
-//!      let bytes = w.finish();
+//!      html( &mut w, b"<p><b>Bold Text Before Image</b> <img width=32 src=myimg> Text after image" ).unwrap();
+//!      let bytes = w.finish().unwrap();
 //!   
 //!      use std::fs::File;
 //!      use std::io::prelude::*;
@@ -48,10 +48,10 @@
 //!
 //!    use jpeg_decoder::{PixelFormat};
 //!    
-//!    let color_space: &[u8] = match info.pixel_format {
-//!        PixelFormat::RGB24 => b"/DeviceRGB",
-//!        PixelFormat::CMYK32 => b"/DeviceCMYK",
-//!        PixelFormat::L8 | PixelFormat::L16 => b"/DeviceGray",
+//!    let color_space = match info.pixel_format {
+//!        PixelFormat::RGB24 => "DeviceRGB",
+//!        PixelFormat::CMYK32 => "DeviceCMYK",
+//!        PixelFormat::L8 | PixelFormat::L16 => "DeviceGray",
 //!    };
 //!
 //!    let bits_per_component = match info.pixel_format {
@@ -60,14 +60,14 @@
 //!    };
 //!
 //!    // Make the ImageSpec.
-//!    use pdf_min::{Px, image::{ImageSpec, Image}};
+//!    use pdf_min::{Px, object::{Dict, Object}, image::{ImageSpec, Image}};
 //!    let ims = ImageSpec {
 //!        data: &file_bytes,
 //!        width: info.width as Px,
 //!        height: info.height as Px,
-//!        color_space,
+//!        color_space: Object::name(color_space),
 //!        bits_per_component,
-//!        other: b"/Filter/DCT",
+//!        other: Dict::new().with("Filter", Object::name("DCTDecode")),
 //!    };
 //!    
 //!    // Make the PDF Image from the ImageSpec.
@@ -77,7 +77,7 @@
 //!    im.draw(&mut doc.p, 20.0, 40.0, 0.20);
 //!
 //!    // Save the pdf as a file.
-//!    let bytes = doc.finish();
+//!    let bytes = doc.finish().unwrap();
 //!    let mut file = std::fs::File::create("jpg_image_test.pdf").unwrap();
 //!    use std::io::Write;
 //!    file.write_all(bytes).unwrap();
@@ -86,7 +86,6 @@
 use crate::BasicPdfWriter;
 use crate::page::Page;
 use crate::*;
-use format_bytes::write_bytes as wb;
 
 /// PDF image specification - byte data and attributes that describe how image is encoded.
 pub struct ImageSpec<'a> {
@@ -98,10 +97,10 @@ pub struct ImageSpec<'a> {
     pub height: Px,
     /// Bits per component, usually 8
     pub bits_per_component: u8,
-    /// Color space, such as b"/DeviceGray", b"/DeviceRGB", b"/DeviceCMYK"
-    pub color_space: &'a [u8],
-    /// Any other attributes, e.g. b"/Filter/DCT" for a jpeg
-    pub other: &'a [u8],
+    /// Color space, such as Object::name("DeviceGray"), Object::name("DeviceRGB"), Object::name("DeviceCMYK")
+    pub color_space: Object,
+    /// Any other attributes, e.g. /Filter/DCTDecode for a jpeg
+    pub other: Dict,
 }
 
 /// PDF image - obj id, width and height
@@ -118,15 +117,16 @@ pub struct Image {
 impl Image {
     /// Writes the specified image attributes and data to the PDF, returns Image with obj id, width and height.
     pub fn new(s: &ImageSpec, w: &mut BasicPdfWriter) -> Image {
-        let obj = w.begin();
-        let _ = wb!(
-            &mut w.b,
-            b"<</Type/XObject/Subtype/Image/Width {}/Height {}/ColorSpace{}/BitsPerComponent {}/Length {}{}>>stream\n",
-            s.width, s.height, s.color_space, s.bits_per_component, s.data.len(), s.other
-        );
-        w.b.extend_from_slice(s.data);
-        w.b.extend_from_slice(b"\nendstream");
-        w.end();
+        let obj = w.obj();
+        let mut d = Dict::new()
+            .with("Type", Object::name("XObject"))
+            .with("Subtype", Object::name("Image"))
+            .with("Width", s.width)
+            .with("Height", s.height)
+            .with("BitsPerComponent", s.bits_per_component as i32)
+            .with("ColorSpace", s.color_space.clone());
+        d.0.extend(s.other.0.iter().cloned());
+        w.raw_stream_obj(obj, &d, s.data);
         Image {
             obj,
             width: s.width,
@@ -139,14 +139,10 @@ impl Image {
         let w = (self.width as f32) * scale;
         let h = (self.height as f32) * scale;
         page.xobjs.insert(self.obj);
-        let _ = wb!(
-            &mut page.os,
-            b"\nq {} 0 0 {} {} {} cm /X{} Do Q",
-            w,
-            h,
-            x,
-            y,
-            self.obj
-        );
+        page.os.push(b'\n');
+        op(&[], "q", &mut page.os);
+        op(&[w.into(), 0.into(), 0.into(), h.into(), x.into(), y.into()], "cm", &mut page.os);
+        op(&[Object::name(&format!("X{}", self.obj))], "Do", &mut page.os);
+        op(&[], "Q", &mut page.os);
     }
 }
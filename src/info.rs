@@ -0,0 +1,170 @@
+//!# Deterministic output example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    let make = || {
+//!        let mut w = Writer::default();
+//!        w.b.deterministic(None);
+//!        html(&mut w, b"<title>Report</title><p>Same every time").unwrap();
+//!        w.finish().unwrap().to_vec()
+//!    };
+//!    let bytes = make();
+//!    assert!(bytes == make());
+//!    assert!(bytes.windows(4).any(|x| x == b"/ID["));
+//!
+//!    // The RC4 key is derived from the /ID, so the /ID of encrypted output is random.
+//!    let make = || {
+//!        let mut w = Writer::default();
+//!        w.b.deterministic(None);
+//!        w.encrypt(&crypt::Security {
+//!            cipher: crypt::Cipher::Rc4,
+//!            user_password: "secret".to_string(),
+//!            owner_password: String::new(),
+//!            permissions: crypt::ALL,
+//!        })
+//!        .unwrap();
+//!        html(&mut w, b"<p>Payslip").unwrap();
+//!        let bytes = w.finish().unwrap().to_vec();
+//!        let id = bytes.windows(4).position(|x| x == b"/ID[").unwrap();
+//!        bytes[id..id + 40].to_vec()
+//!    };
+//!    assert!(make() != make());
+//! ```
+
+/// Document information ( written to the PDF Info dictionary ).
+#[derive(Clone, Default)]
+pub struct DocInfo {
+    /// Title
+    pub title: String,
+    /// Author
+    pub author: String,
+    /// Subject ( e.g. from html meta description )
+    pub subject: String,
+    /// Keywords
+    pub keywords: String,
+    /// Application that created the original document
+    pub creator: String,
+    /// Application that produced the PDF, default is "pdf-min"
+    pub producer: String,
+    /// Creation date, default ( None ) is the time the PDF is finished.
+    pub creation_date: Option<PdfDate>,
+    /// Modification date, default ( None ) is the creation date.
+    pub mod_date: Option<PdfDate>,
+    /// Natural language ( e.g. "en-GB" from html lang ), written to the catalog /Lang.
+    pub lang: String,
+}
+
+impl DocInfo {
+    /// New DocInfo with producer set to "pdf-min".
+    pub fn new() -> Self {
+        Self {
+            producer: "pdf-min".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// List of (key,value) pairs for non-empty text fields.
+    pub fn fields(&self) -> Vec<(&'static [u8], &str)> {
+        let mut result = Vec::new();
+        for (k, v) in [
+            (&b"Title"[..], &self.title),
+            (b"Author", &self.author),
+            (b"Subject", &self.subject),
+            (b"Keywords", &self.keywords),
+            (b"Creator", &self.creator),
+            (b"Producer", &self.producer),
+        ] {
+            if !v.is_empty() {
+                result.push((k, v.as_str()));
+            }
+        }
+        result
+    }
+}
+
+/// Date and time ( as used in PDF date strings ).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PdfDate {
+    /// Year, e.g. 2024
+    pub year: u16,
+    /// Month 1..=12
+    pub month: u8,
+    /// Day 1..=31
+    pub day: u8,
+    /// Hour 0..=23
+    pub hour: u8,
+    /// Minute 0..=59
+    pub minute: u8,
+    /// Second 0..=59
+    pub second: u8,
+    /// Offset from UT in minutes ( e.g. 60 for UT+01:00 ).
+    pub offset: i16,
+}
+
+impl PdfDate {
+    /// Current time ( UT ).
+    pub fn now() -> Self {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Self::from_unix(secs)
+    }
+
+    /// Time ( UT ) given as seconds since 1970-01-01.
+    pub fn from_unix(secs: u64) -> Self {
+        let days = (secs / 86400) as i64;
+        let rem = secs % 86400;
+        // Civil from days, see http://howardhinnant.github.io/date_algorithms.html
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as u16;
+        Self {
+            year,
+            month,
+            day,
+            hour: (rem / 3600) as u8,
+            minute: (rem / 60 % 60) as u8,
+            second: (rem % 60) as u8,
+            offset: 0,
+        }
+    }
+
+    /// Format as PDF date string, e.g. "D:20240131235959Z" or "D:20240131235959+01'00'".
+    pub fn pdf(&self) -> String {
+        let mut s = format!(
+            "D:{:04}{:02}{:02}{:02}{:02}{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        );
+        if self.offset == 0 {
+            s.push('Z');
+        } else {
+            let sign = if self.offset < 0 { '-' } else { '+' };
+            let m = self.offset.unsigned_abs();
+            s += &format!("{}{:02}'{:02}'", sign, m / 60, m % 60);
+        }
+        s
+    }
+
+    /// Format as ISO 8601 date ( as used in XMP ), e.g. "2024-01-31T23:59:59Z" or "2024-01-31T23:59:59+01:00".
+    pub fn iso(&self) -> String {
+        let mut s = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        );
+        if self.offset == 0 {
+            s.push('Z');
+        } else {
+            let sign = if self.offset < 0 { '-' } else { '+' };
+            let m = self.offset.unsigned_abs();
+            s += &format!("{}{:02}:{:02}", sign, m / 60, m % 60);
+        }
+        s
+    }
+}
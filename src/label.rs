@@ -0,0 +1,149 @@
+//!# Page label example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::label::*;
+//!    let mut w = Writer::default();
+//!    w.b.nocomp = true;
+//!    w.page_labels(LabelStyle::RomanLower, "", 1);
+//!    html(&mut w, b"<h1>Contents</h1>").unwrap();
+//!    w.page_break();
+//!    html(&mut w, b"<p>Preface").unwrap();
+//!    w.page_labels(LabelStyle::Decimal, "", 1);
+//!    w.page_break();
+//!    html(&mut w, b"<p>Chapter 1").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    let has = |s: &[u8]| bytes.windows(s.len()).any(|x| x == s);
+//!    assert!(has(b"/Nums[0<</S/r>>2<</S/D>>]"));
+//!    // The page count in the footer is in the same style as the page number.
+//!    assert!(has(b"age ii of )] TJ") && has(b"(ii) Tj"));
+//!    assert!(has(b"age 1 of )] TJ") && has(b"(1) Tj"));
+//! ```
+
+use crate::*;
+
+/// Numbering style for page labels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LabelStyle {
+    /// 1, 2, 3
+    Decimal,
+    /// I, II, III
+    RomanUpper,
+    /// i, ii, iii
+    RomanLower,
+    /// A, B, ... Z, AA, BB
+    AlphaUpper,
+    /// a, b, ... z, aa, bb
+    AlphaLower,
+    /// No number, label is just the prefix
+    None,
+}
+
+/// Page label range, applies from a page index until the next range.
+#[derive(Clone, Debug)]
+pub struct PageLabel {
+    /// Index of first page in range ( zero based ).
+    pub page: usize,
+    /// Numbering style.
+    pub style: LabelStyle,
+    /// Prefix, e.g. "A-"
+    pub prefix: String,
+    /// Number of first page in range, usually 1 ( PDF requires at least 1, so 0 is treated as 1 ).
+    pub start: usize,
+}
+
+impl PageLabel {
+    /// Label of page with given index ( which should be in this range ).
+    pub fn label(&self, page: usize) -> String {
+        let n = self.start.max(1) + page - self.page;
+        let num = match self.style {
+            LabelStyle::Decimal => n.to_string(),
+            LabelStyle::RomanUpper => roman(n),
+            LabelStyle::RomanLower => roman(n).to_lowercase(),
+            LabelStyle::AlphaUpper => alpha(n),
+            LabelStyle::AlphaLower => alpha(n).to_lowercase(),
+            LabelStyle::None => String::new(),
+        };
+        self.prefix.clone() + &num
+    }
+}
+
+/// Roman numeral, e.g. 14 is "XIV".
+fn roman(mut n: usize) -> String {
+    let mut s = String::new();
+    for (v, r) in [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ] {
+        while n >= v {
+            s += r;
+            n -= v;
+        }
+    }
+    s
+}
+
+/// Alphabetic label as used by PDF viewers, e.g. 1 is "A", 27 is "AA".
+fn alpha(n: usize) -> String {
+    if n == 0 {
+        return String::new();
+    }
+    let c = (b'A' + ((n - 1) % 26) as u8) as char;
+    c.to_string().repeat((n - 1) / 26 + 1)
+}
+
+impl BasicPdfWriter {
+    /// Label of page with given index ( zero based ), decimal page number if no labels are defined.
+    pub fn page_label(&self, page: usize) -> String {
+        match self.labels.iter().rev().find(|r| r.page <= page) {
+            Some(r) => r.label(page),
+            None => (page + 1).to_string(),
+        }
+    }
+
+    /// Write page labels number tree, result is obj number ( zero if there are no labels ).
+    pub fn page_labels(&mut self) -> usize {
+        if self.labels.is_empty() {
+            return 0;
+        }
+        let mut nums = Vec::new();
+        if self.labels[0].page != 0 {
+            nums.push(Object::Int(0));
+            nums.push(Object::Dict(Dict::new().with("S", Object::name("D"))));
+        }
+        for r in std::mem::take(&mut self.labels) {
+            let mut d = Dict::new();
+            let style = match r.style {
+                LabelStyle::Decimal => Some("D"),
+                LabelStyle::RomanUpper => Some("R"),
+                LabelStyle::RomanLower => Some("r"),
+                LabelStyle::AlphaUpper => Some("A"),
+                LabelStyle::AlphaLower => Some("a"),
+                LabelStyle::None => None,
+            };
+            if let Some(s) = style {
+                d.set("S", Object::name(s));
+            }
+            if !r.prefix.is_empty() {
+                d.set("P", Object::text(&r.prefix));
+            }
+            if r.start > 1 {
+                d.set("St", r.start);
+            }
+            nums.push(Object::from(r.page));
+            nums.push(Object::Dict(d));
+        }
+        self.add_obj(&Object::Dict(Dict::new().with("Nums", nums)))
+    }
+}
@@ -11,6 +11,7 @@
 //!<html>
 //!<head>
 //!   <title>Rust is Great</title>
+//!   <meta name=author content=\"George Barwood\">
 //!</head>
 //!<body>
 //!<h1>Important Notice&excl;</h1>
@@ -26,8 +27,8 @@
 //!    let mut w = Writer::default();
 //!    w.b.nocomp = true; // w.fonts = font::times();
 //!    w.line_pad = 8; // Other Writer default values could be adjusted here.
-//!    html(&mut w, source.as_bytes());
-//!    let bytes = w.finish();
+//!    html(&mut w, source.as_bytes()).unwrap();
+//!    let bytes = w.finish().unwrap();
 //!
 //!    use std::fs::File;
 //!    use std::io::prelude::*;
@@ -39,30 +40,82 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+/// Annotations ( links ).
+pub mod annot;
+/// Embedded files ( attachments ).
+pub mod attach;
 /// Low level PDF writer.
 pub mod basic;
+/// Encryption ( standard security handler ).
+pub mod crypt;
+/// Error type.
+pub mod error;
+/// Interactive forms ( AcroForm fields ).
+pub mod form;
 /// PDF fonts.
 pub mod font;
 /// Conversion from HTML to PDF.
 pub mod html;
 /// PDF images.
 pub mod image;
+/// Document information.
+pub mod info;
+/// Page labels.
+pub mod label;
+/// Printer's marks ( crop and registration marks ).
+pub mod marks;
 /// Character sizes for standard fonts.
 pub mod metric;
+/// PDF object model.
+pub mod object;
+/// OpenType font embedding ( CFF outlines ).
+pub mod otf;
+/// Document outline ( bookmarks ).
+pub mod outline;
 /// PDF page.
 pub mod page;
+/// Paper sizes and page setup.
+pub mod paper;
+/// PDF/A conformance.
+pub mod pdfa;
+/// Digital signatures.
+pub mod sign;
+/// Tagged PDF ( structure tree for accessibility ).
+pub mod tag;
+/// TrueType font embedding.
+pub mod ttf;
+/// Viewer settings ( page mode, layout, open action and preferences ).
+pub mod viewer;
 /// High level PDF writer.
 pub mod writer;
+/// XMP metadata.
+pub mod xmp;
 
 pub use html::html;
 pub use writer::Writer;
 
 use std::collections::BTreeMap;
+use annot::*;
+use attach::*;
 use basic::*;
+use crypt::*;
+use error::*;
 use font::*;
+use form::*;
 use image::*;
+use info::*;
+use label::*;
+use marks::*;
+use object::*;
+use outline::*;
+use paper::*;
 use page::*;
+use pdfa::*;
+use sign::*;
+use tag::*;
+use viewer::*;
 use writer::*;
+use xmp::*;
 
 /// Page unit
 pub type Px = i32;
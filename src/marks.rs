@@ -0,0 +1,107 @@
+//!# Printer's marks example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::marks::PrintMarks;
+//!    let mut w = Writer::default();
+//!    w.print_marks = Some(PrintMarks::default());
+//!    html(&mut w, b"<p>Full bleed brochure").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(15).any(|x| x == b"/TrimBox[0 0 60"));
+//! ```
+
+use crate::*;
+
+/// Printer's marks settings ( sizes are in points ).
+#[derive(Clone, Copy, Debug)]
+pub struct PrintMarks {
+    /// Bleed margin outside the trim box, default is 8.5 ( 3mm ).
+    pub bleed: f32,
+    /// Gap between the bleed box and the marks, default is 3.
+    pub offset: f32,
+    /// Length of crop marks, default is 18.
+    pub length: f32,
+    /// Draw registration marks ( centred on each side ), default is true.
+    pub registration: bool,
+}
+
+impl Default for PrintMarks {
+    fn default() -> Self {
+        Self {
+            bleed: 8.5,
+            offset: 3.0,
+            length: 18.0,
+            registration: true,
+        }
+    }
+}
+
+impl PrintMarks {
+    /// Distance from the trim box to the edge of the media box.
+    pub fn margin(&self) -> f32 {
+        self.bleed + self.offset + self.length
+    }
+}
+
+impl Page {
+    /// Set the trim box to the page, grow the bleed and media boxes and draw crop and registration marks
+    /// outside the bleed box. Marks use registration colour ( all CMYK inks ).
+    pub fn print_marks(&mut self, m: &PrintMarks) {
+        let (w, h) = (self.width as f32, self.height as f32);
+        let (b, s) = (m.bleed, m.margin());
+        self.trim_box = Some([0.0, 0.0, w, h]);
+        self.bleed_box = Some([-b, -b, w + b, h + b]);
+        self.media_box = Some([-s, -s, w + s, h + s]);
+
+        self.os.extend_from_slice(b"\nq 0.25 w 1 1 1 1 K");
+        let d = b + m.offset;
+        for (x, y, sx, sy) in [
+            (0.0, 0.0, -1.0, -1.0),
+            (w, 0.0, 1.0, -1.0),
+            (0.0, h, -1.0, 1.0),
+            (w, h, 1.0, 1.0),
+        ] {
+            self.mark_line(x + sx * d, y, x + sx * s, y);
+            self.mark_line(x, y + sy * d, x, y + sy * s);
+        }
+        if m.registration {
+            let c = d + m.length / 2.0;
+            let r = m.length / 4.0;
+            for (x, y) in [
+                (w / 2.0, -c),
+                (w / 2.0, h + c),
+                (-c, h / 2.0),
+                (w + c, h / 2.0),
+            ] {
+                self.target(x, y, r);
+            }
+        }
+        self.os.extend_from_slice(b"\nQ");
+    }
+
+    fn mark_line(&mut self, x0: f32, y0: f32, x1: f32, y1: f32) {
+        self.line(x0 as f64, y0 as f64, x1 as f64, y1 as f64);
+    }
+
+    /// Registration target ( circle of radius r with cross lines ) centred at (x,y).
+    fn target(&mut self, x: f32, y: f32, r: f32) {
+        // Circle drawn as four Bezier curves.
+        let k = r * 0.5523;
+        self.os.push(b'\n');
+        op(&[(x + r).into(), y.into()], "m", &mut self.os);
+        let curves = [
+            [x + r, y + k, x + k, y + r, x, y + r],
+            [x - k, y + r, x - r, y + k, x - r, y],
+            [x - r, y - k, x - k, y - r, x, y - r],
+            [x + k, y - r, x + r, y - k, x + r, y],
+        ];
+        for c in curves {
+            let c: Vec<Object> = c.into_iter().map(Object::from).collect();
+            op(&c, "c", &mut self.os);
+        }
+        op(&[], "S", &mut self.os);
+        let t = r * 1.5;
+        self.mark_line(x - t, y, x + t, y);
+        self.mark_line(x, y - t, x, y + t);
+    }
+}
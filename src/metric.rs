@@ -1,3 +1,5 @@
+use crate::font::FontMetrics;
+
 /// Width data
 pub static COURIER0: [u16; 1] = [600];
 
@@ -1469,3 +1471,1873 @@ pub static SYMBOL: [u16; 224] = [
     /* C 254 , WX */ 494, /* , N  bracerightbt , B 48 -75 284 935 ; */
     500,
 ];
+
+/// Kerning data for Helvetica ( also Helvetica-Oblique ), sorted ( first char, second char, adjustment ).
+pub static HELVETICA_KERN0: [(char, char, i16); 221] = [
+    (' ', 'T', -50),
+    (' ', 'V', -50),
+    (' ', 'W', -40),
+    (' ', 'Y', -90),
+    (' ', '\u{2018}', -60),
+    (' ', '\u{201c}', -30),
+    (',', '\u{2019}', -100),
+    (',', '\u{201d}', -100),
+    ('.', ' ', -60),
+    ('.', '\u{2019}', -100),
+    ('.', '\u{201d}', -100),
+    (':', ' ', -50),
+    (';', ' ', -50),
+    ('A', 'C', -30),
+    ('A', 'G', -30),
+    ('A', 'O', -30),
+    ('A', 'Q', -30),
+    ('A', 'T', -120),
+    ('A', 'U', -50),
+    ('A', 'V', -70),
+    ('A', 'W', -50),
+    ('A', 'Y', -100),
+    ('A', 'u', -30),
+    ('A', 'v', -40),
+    ('A', 'w', -40),
+    ('A', 'y', -40),
+    ('B', ',', -20),
+    ('B', '.', -20),
+    ('B', 'U', -10),
+    ('C', ',', -30),
+    ('C', '.', -30),
+    ('D', ',', -70),
+    ('D', '.', -70),
+    ('D', 'A', -40),
+    ('D', 'V', -70),
+    ('D', 'W', -40),
+    ('D', 'Y', -90),
+    ('F', ',', -150),
+    ('F', '.', -150),
+    ('F', 'A', -80),
+    ('F', 'a', -50),
+    ('F', 'e', -30),
+    ('F', 'o', -30),
+    ('F', 'r', -45),
+    ('J', ',', -30),
+    ('J', '.', -30),
+    ('J', 'A', -20),
+    ('J', 'a', -20),
+    ('J', 'u', -20),
+    ('K', 'O', -50),
+    ('K', 'e', -40),
+    ('K', 'o', -40),
+    ('K', 'u', -30),
+    ('K', 'y', -50),
+    ('L', 'T', -110),
+    ('L', 'V', -110),
+    ('L', 'W', -70),
+    ('L', 'Y', -140),
+    ('L', 'y', -30),
+    ('L', '\u{2019}', -160),
+    ('L', '\u{201d}', -140),
+    ('O', ',', -40),
+    ('O', '.', -40),
+    ('O', 'A', -20),
+    ('O', 'T', -40),
+    ('O', 'V', -50),
+    ('O', 'W', -30),
+    ('O', 'X', -60),
+    ('O', 'Y', -70),
+    ('P', ',', -180),
+    ('P', '.', -180),
+    ('P', 'A', -120),
+    ('P', 'a', -40),
+    ('P', 'e', -50),
+    ('P', 'o', -50),
+    ('Q', 'U', -10),
+    ('R', 'O', -20),
+    ('R', 'T', -30),
+    ('R', 'U', -40),
+    ('R', 'V', -50),
+    ('R', 'W', -30),
+    ('R', 'Y', -50),
+    ('S', ',', -20),
+    ('S', '.', -20),
+    ('T', ',', -120),
+    ('T', '-', -140),
+    ('T', '.', -120),
+    ('T', ':', -20),
+    ('T', ';', -20),
+    ('T', 'A', -120),
+    ('T', 'O', -40),
+    ('T', 'a', -120),
+    ('T', 'e', -120),
+    ('T', 'o', -120),
+    ('T', 'r', -120),
+    ('T', 'u', -120),
+    ('T', 'w', -120),
+    ('T', 'y', -120),
+    ('U', ',', -40),
+    ('U', '.', -40),
+    ('U', 'A', -40),
+    ('V', ',', -125),
+    ('V', '-', -80),
+    ('V', '.', -125),
+    ('V', ':', -40),
+    ('V', ';', -40),
+    ('V', 'A', -80),
+    ('V', 'G', -40),
+    ('V', 'O', -40),
+    ('V', 'a', -70),
+    ('V', 'e', -80),
+    ('V', 'o', -80),
+    ('V', 'u', -70),
+    ('W', ',', -80),
+    ('W', '-', -40),
+    ('W', '.', -80),
+    ('W', 'A', -50),
+    ('W', 'O', -20),
+    ('W', 'a', -40),
+    ('W', 'e', -30),
+    ('W', 'o', -30),
+    ('W', 'u', -30),
+    ('W', 'y', -20),
+    ('Y', ',', -140),
+    ('Y', '-', -140),
+    ('Y', '.', -140),
+    ('Y', ':', -60),
+    ('Y', ';', -60),
+    ('Y', 'A', -110),
+    ('Y', 'O', -85),
+    ('Y', 'a', -140),
+    ('Y', 'e', -140),
+    ('Y', 'i', -20),
+    ('Y', 'o', -140),
+    ('Y', 'u', -110),
+    ('a', 'v', -20),
+    ('a', 'w', -20),
+    ('a', 'y', -30),
+    ('b', ',', -40),
+    ('b', '.', -40),
+    ('b', 'b', -10),
+    ('b', 'l', -20),
+    ('b', 'u', -20),
+    ('b', 'v', -20),
+    ('b', 'y', -20),
+    ('c', ',', -15),
+    ('c', 'k', -20),
+    ('e', ',', -15),
+    ('e', '.', -15),
+    ('e', 'v', -30),
+    ('e', 'w', -20),
+    ('e', 'x', -30),
+    ('e', 'y', -20),
+    ('f', ',', -30),
+    ('f', '.', -30),
+    ('f', 'a', -30),
+    ('f', 'e', -30),
+    ('f', 'o', -30),
+    ('f', '\u{2019}', 50),
+    ('f', '\u{201d}', 60),
+    ('g', 'r', -10),
+    ('h', 'y', -30),
+    ('k', 'e', -20),
+    ('k', 'o', -20),
+    ('m', 'u', -10),
+    ('m', 'y', -15),
+    ('n', 'u', -10),
+    ('n', 'v', -20),
+    ('n', 'y', -15),
+    ('o', ',', -40),
+    ('o', '.', -40),
+    ('o', 'v', -15),
+    ('o', 'w', -15),
+    ('o', 'x', -30),
+    ('o', 'y', -30),
+    ('p', ',', -35),
+    ('p', '.', -35),
+    ('p', 'y', -30),
+    ('r', ',', -50),
+    ('r', '.', -50),
+    ('r', ':', 30),
+    ('r', ';', 30),
+    ('r', 'a', -10),
+    ('r', 'i', 15),
+    ('r', 'k', 15),
+    ('r', 'l', 15),
+    ('r', 'm', 25),
+    ('r', 'n', 25),
+    ('r', 'p', 30),
+    ('r', 't', 40),
+    ('r', 'u', 15),
+    ('r', 'v', 30),
+    ('r', 'y', 30),
+    ('s', ',', -15),
+    ('s', '.', -15),
+    ('s', 'w', -30),
+    ('v', ',', -80),
+    ('v', '.', -80),
+    ('v', 'a', -25),
+    ('v', 'e', -25),
+    ('v', 'o', -25),
+    ('w', ',', -60),
+    ('w', '.', -60),
+    ('w', 'a', -15),
+    ('w', 'e', -10),
+    ('w', 'o', -10),
+    ('x', 'e', -30),
+    ('y', ',', -100),
+    ('y', '.', -100),
+    ('y', 'a', -20),
+    ('y', 'e', -20),
+    ('y', 'o', -20),
+    ('z', 'e', -15),
+    ('z', 'o', -15),
+    ('\u{2018}', '\u{2018}', -57),
+    ('\u{2019}', ' ', -70),
+    ('\u{2019}', 'd', -50),
+    ('\u{2019}', 'r', -50),
+    ('\u{2019}', 's', -50),
+    ('\u{2019}', '\u{2019}', -57),
+    ('\u{201d}', ' ', -40),
+];
+
+/// Kerning data for Helvetica-Bold ( also Helvetica-BoldOblique ), sorted ( first char, second char, adjustment ).
+pub static HELVETICA_KERN1: [(char, char, i16); 207] = [
+    (' ', 'T', -100),
+    (' ', 'V', -80),
+    (' ', 'W', -80),
+    (' ', 'Y', -120),
+    (' ', '\u{2018}', -60),
+    (' ', '\u{201c}', -80),
+    (',', ' ', -40),
+    (',', '\u{2019}', -120),
+    (',', '\u{201d}', -120),
+    ('.', ' ', -40),
+    ('.', '\u{2019}', -120),
+    ('.', '\u{201d}', -120),
+    (':', ' ', -40),
+    (';', ' ', -40),
+    ('A', 'C', -40),
+    ('A', 'G', -50),
+    ('A', 'O', -40),
+    ('A', 'Q', -40),
+    ('A', 'T', -90),
+    ('A', 'U', -50),
+    ('A', 'V', -80),
+    ('A', 'W', -60),
+    ('A', 'Y', -110),
+    ('A', 'u', -30),
+    ('A', 'v', -40),
+    ('A', 'w', -30),
+    ('A', 'y', -30),
+    ('B', 'A', -30),
+    ('B', 'U', -10),
+    ('D', ',', -30),
+    ('D', '.', -30),
+    ('D', 'A', -40),
+    ('D', 'V', -40),
+    ('D', 'W', -40),
+    ('D', 'Y', -70),
+    ('F', ',', -100),
+    ('F', '.', -100),
+    ('F', 'A', -80),
+    ('F', 'a', -20),
+    ('J', ',', -20),
+    ('J', '.', -20),
+    ('J', 'A', -20),
+    ('J', 'u', -20),
+    ('K', 'O', -30),
+    ('K', 'e', -15),
+    ('K', 'o', -35),
+    ('K', 'u', -30),
+    ('K', 'y', -40),
+    ('L', 'T', -90),
+    ('L', 'V', -110),
+    ('L', 'W', -80),
+    ('L', 'Y', -120),
+    ('L', 'y', -30),
+    ('L', '\u{2019}', -140),
+    ('L', '\u{201d}', -140),
+    ('O', ',', -40),
+    ('O', '.', -40),
+    ('O', 'A', -50),
+    ('O', 'T', -40),
+    ('O', 'V', -50),
+    ('O', 'W', -50),
+    ('O', 'X', -50),
+    ('O', 'Y', -70),
+    ('P', ',', -120),
+    ('P', '.', -120),
+    ('P', 'A', -100),
+    ('P', 'a', -30),
+    ('P', 'e', -30),
+    ('P', 'o', -40),
+    ('Q', ',', 20),
+    ('Q', '.', 20),
+    ('Q', 'U', -10),
+    ('R', 'O', -20),
+    ('R', 'T', -20),
+    ('R', 'U', -20),
+    ('R', 'V', -50),
+    ('R', 'W', -40),
+    ('R', 'Y', -50),
+    ('T', ',', -80),
+    ('T', '-', -120),
+    ('T', '.', -80),
+    ('T', ':', -40),
+    ('T', ';', -40),
+    ('T', 'A', -90),
+    ('T', 'O', -40),
+    ('T', 'a', -80),
+    ('T', 'e', -60),
+    ('T', 'o', -80),
+    ('T', 'r', -80),
+    ('T', 'u', -90),
+    ('T', 'w', -60),
+    ('T', 'y', -60),
+    ('U', ',', -30),
+    ('U', '.', -30),
+    ('U', 'A', -50),
+    ('V', ',', -120),
+    ('V', '-', -80),
+    ('V', '.', -120),
+    ('V', ':', -40),
+    ('V', ';', -40),
+    ('V', 'A', -80),
+    ('V', 'G', -50),
+    ('V', 'O', -50),
+    ('V', 'a', -60),
+    ('V', 'e', -50),
+    ('V', 'o', -90),
+    ('V', 'u', -60),
+    ('W', ',', -80),
+    ('W', '-', -40),
+    ('W', '.', -80),
+    ('W', 'A', -60),
+    ('W', 'O', -20),
+    ('W', 'a', -40),
+    ('W', 'e', -35),
+    ('W', 'o', -60),
+    ('W', 'u', -45),
+    ('W', 'y', -20),
+    ('Y', ',', -100),
+    ('Y', '.', -100),
+    ('Y', ':', -50),
+    ('Y', ';', -50),
+    ('Y', 'A', -110),
+    ('Y', 'O', -70),
+    ('Y', 'a', -90),
+    ('Y', 'e', -80),
+    ('Y', 'o', -100),
+    ('Y', 'u', -100),
+    ('a', 'g', -10),
+    ('a', 'v', -15),
+    ('a', 'w', -15),
+    ('a', 'y', -20),
+    ('b', 'l', -10),
+    ('b', 'u', -20),
+    ('b', 'v', -20),
+    ('b', 'y', -20),
+    ('c', 'h', -10),
+    ('c', 'k', -20),
+    ('c', 'l', -20),
+    ('c', 'y', -10),
+    ('d', 'd', -10),
+    ('d', 'v', -15),
+    ('d', 'w', -15),
+    ('d', 'y', -15),
+    ('e', ',', 10),
+    ('e', '.', 20),
+    ('e', 'v', -15),
+    ('e', 'w', -15),
+    ('e', 'x', -15),
+    ('e', 'y', -15),
+    ('f', ',', -10),
+    ('f', '.', -10),
+    ('f', 'e', -10),
+    ('f', 'o', -20),
+    ('f', '\u{2019}', 30),
+    ('f', '\u{201d}', 30),
+    ('g', 'e', 10),
+    ('g', 'g', -10),
+    ('h', 'y', -20),
+    ('k', 'o', -15),
+    ('l', 'w', -15),
+    ('l', 'y', -15),
+    ('m', 'u', -20),
+    ('m', 'y', -30),
+    ('n', 'u', -10),
+    ('n', 'v', -40),
+    ('n', 'y', -20),
+    ('o', 'v', -20),
+    ('o', 'w', -15),
+    ('o', 'x', -30),
+    ('o', 'y', -20),
+    ('p', 'y', -15),
+    ('r', ',', -60),
+    ('r', '-', -20),
+    ('r', '.', -60),
+    ('r', 'c', -20),
+    ('r', 'd', -20),
+    ('r', 'g', -15),
+    ('r', 'o', -20),
+    ('r', 'q', -20),
+    ('r', 's', -15),
+    ('r', 't', 20),
+    ('r', 'v', 10),
+    ('r', 'y', 10),
+    ('s', 'w', -15),
+    ('v', ',', -80),
+    ('v', '.', -80),
+    ('v', 'a', -20),
+    ('v', 'o', -30),
+    ('w', ',', -40),
+    ('w', '.', -40),
+    ('w', 'o', -20),
+    ('x', 'e', -10),
+    ('y', ',', -80),
+    ('y', '.', -80),
+    ('y', 'a', -30),
+    ('y', 'e', -10),
+    ('y', 'o', -25),
+    ('z', 'e', 10),
+    ('\u{2018}', '\u{2018}', -46),
+    ('\u{2019}', ' ', -80),
+    ('\u{2019}', 'd', -80),
+    ('\u{2019}', 'l', -20),
+    ('\u{2019}', 'r', -40),
+    ('\u{2019}', 's', -60),
+    ('\u{2019}', 'v', -20),
+    ('\u{2019}', '\u{2019}', -46),
+    ('\u{201d}', ' ', -80),
+];
+
+/// Kerning data for Times-Roman, sorted ( first char, second char, adjustment ).
+pub static TIMES_KERN0: [(char, char, i16); 171] = [
+    (' ', 'A', -55),
+    (' ', 'T', -18),
+    (' ', 'V', -50),
+    (' ', 'W', -30),
+    (' ', 'Y', -90),
+    (',', '\u{2019}', -70),
+    (',', '\u{201d}', -70),
+    ('.', '\u{2019}', -70),
+    ('.', '\u{201d}', -70),
+    ('A', 'C', -40),
+    ('A', 'G', -40),
+    ('A', 'O', -55),
+    ('A', 'Q', -55),
+    ('A', 'T', -111),
+    ('A', 'U', -55),
+    ('A', 'V', -135),
+    ('A', 'W', -90),
+    ('A', 'Y', -105),
+    ('A', 'v', -74),
+    ('A', 'w', -92),
+    ('A', 'y', -92),
+    ('A', '\u{2019}', -111),
+    ('B', 'A', -35),
+    ('B', 'U', -10),
+    ('D', 'A', -40),
+    ('D', 'V', -40),
+    ('D', 'W', -30),
+    ('D', 'Y', -55),
+    ('F', ',', -80),
+    ('F', '.', -80),
+    ('F', 'A', -74),
+    ('F', 'a', -15),
+    ('F', 'o', -15),
+    ('J', 'A', -60),
+    ('K', 'O', -30),
+    ('K', 'e', -25),
+    ('K', 'o', -35),
+    ('K', 'y', -25),
+    ('L', 'T', -92),
+    ('L', 'V', -100),
+    ('L', 'W', -74),
+    ('L', 'Y', -100),
+    ('L', 'y', -55),
+    ('L', '\u{2019}', -92),
+    ('N', 'A', -35),
+    ('O', 'A', -35),
+    ('O', 'T', -40),
+    ('O', 'V', -50),
+    ('O', 'W', -35),
+    ('O', 'X', -40),
+    ('O', 'Y', -50),
+    ('P', ',', -111),
+    ('P', '.', -111),
+    ('P', 'A', -92),
+    ('P', 'a', -15),
+    ('Q', 'U', -10),
+    ('R', 'O', -40),
+    ('R', 'T', -60),
+    ('R', 'U', -40),
+    ('R', 'V', -80),
+    ('R', 'W', -55),
+    ('R', 'Y', -65),
+    ('T', ',', -74),
+    ('T', '-', -92),
+    ('T', '.', -74),
+    ('T', ':', -50),
+    ('T', ';', -55),
+    ('T', 'A', -93),
+    ('T', 'O', -18),
+    ('T', 'a', -80),
+    ('T', 'e', -70),
+    ('T', 'i', -35),
+    ('T', 'o', -80),
+    ('T', 'r', -35),
+    ('T', 'u', -45),
+    ('T', 'w', -80),
+    ('T', 'y', -80),
+    ('U', 'A', -40),
+    ('V', ',', -129),
+    ('V', '-', -100),
+    ('V', '.', -129),
+    ('V', ':', -74),
+    ('V', ';', -74),
+    ('V', 'A', -135),
+    ('V', 'G', -15),
+    ('V', 'O', -40),
+    ('V', 'a', -111),
+    ('V', 'e', -111),
+    ('V', 'i', -60),
+    ('V', 'o', -129),
+    ('V', 'u', -75),
+    ('W', ',', -92),
+    ('W', '-', -65),
+    ('W', '.', -92),
+    ('W', ':', -37),
+    ('W', ';', -37),
+    ('W', 'A', -120),
+    ('W', 'O', -10),
+    ('W', 'a', -80),
+    ('W', 'e', -80),
+    ('W', 'i', -40),
+    ('W', 'o', -80),
+    ('W', 'u', -50),
+    ('W', 'y', -73),
+    ('Y', ',', -129),
+    ('Y', '-', -111),
+    ('Y', '.', -129),
+    ('Y', ':', -92),
+    ('Y', ';', -92),
+    ('Y', 'A', -120),
+    ('Y', 'O', -30),
+    ('Y', 'a', -100),
+    ('Y', 'e', -100),
+    ('Y', 'i', -55),
+    ('Y', 'o', -110),
+    ('Y', 'u', -111),
+    ('a', 'v', -20),
+    ('a', 'w', -15),
+    ('b', '.', -40),
+    ('b', 'u', -20),
+    ('b', 'v', -15),
+    ('c', 'y', -15),
+    ('e', 'g', -15),
+    ('e', 'v', -25),
+    ('e', 'w', -25),
+    ('e', 'x', -15),
+    ('e', 'y', -15),
+    ('f', 'a', -10),
+    ('f', 'f', -25),
+    ('f', 'i', -20),
+    ('f', '\u{2019}', 55),
+    ('g', 'a', -5),
+    ('h', 'y', -5),
+    ('i', 'v', -25),
+    ('k', 'e', -10),
+    ('k', 'o', -10),
+    ('k', 'y', -15),
+    ('l', 'w', -10),
+    ('n', 'v', -40),
+    ('n', 'y', -15),
+    ('o', 'v', -15),
+    ('o', 'w', -25),
+    ('o', 'y', -10),
+    ('p', 'y', -10),
+    ('r', ',', -40),
+    ('r', '-', -20),
+    ('r', '.', -55),
+    ('r', 'g', -18),
+    ('v', ',', -65),
+    ('v', '.', -65),
+    ('v', 'a', -25),
+    ('v', 'e', -15),
+    ('v', 'o', -20),
+    ('w', ',', -65),
+    ('w', '.', -65),
+    ('w', 'a', -10),
+    ('w', 'o', -10),
+    ('x', 'e', -15),
+    ('y', ',', -65),
+    ('y', '.', -65),
+    ('\u{2018}', 'A', -80),
+    ('\u{2018}', '\u{2018}', -74),
+    ('\u{2019}', ' ', -74),
+    ('\u{2019}', 'd', -50),
+    ('\u{2019}', 'l', -10),
+    ('\u{2019}', 'r', -50),
+    ('\u{2019}', 's', -55),
+    ('\u{2019}', 't', -18),
+    ('\u{2019}', 'v', -50),
+    ('\u{2019}', '\u{2019}', -74),
+    ('\u{201c}', 'A', -80),
+];
+
+/// Kerning data for Times-Bold, sorted ( first char, second char, adjustment ).
+pub static TIMES_KERN1: [(char, char, i16); 178] = [
+    (' ', 'A', -55),
+    (' ', 'T', -30),
+    (' ', 'V', -45),
+    (' ', 'W', -30),
+    (' ', 'Y', -55),
+    (',', '\u{2019}', -55),
+    (',', '\u{201d}', -45),
+    ('.', '\u{2019}', -55),
+    ('.', '\u{201d}', -55),
+    ('A', 'C', -55),
+    ('A', 'G', -55),
+    ('A', 'O', -45),
+    ('A', 'Q', -45),
+    ('A', 'T', -95),
+    ('A', 'U', -50),
+    ('A', 'V', -145),
+    ('A', 'W', -130),
+    ('A', 'Y', -100),
+    ('A', 'u', -50),
+    ('A', 'v', -100),
+    ('A', 'w', -90),
+    ('A', 'y', -74),
+    ('A', '\u{2019}', -74),
+    ('B', 'A', -30),
+    ('B', 'U', -10),
+    ('D', '.', -20),
+    ('D', 'A', -35),
+    ('D', 'V', -40),
+    ('D', 'W', -40),
+    ('D', 'Y', -40),
+    ('F', ',', -92),
+    ('F', '.', -110),
+    ('F', 'A', -90),
+    ('F', 'a', -25),
+    ('F', 'e', -25),
+    ('F', 'o', -25),
+    ('J', '.', -20),
+    ('J', 'A', -30),
+    ('J', 'a', -15),
+    ('J', 'e', -15),
+    ('J', 'u', -15),
+    ('K', 'O', -30),
+    ('K', 'e', -25),
+    ('K', 'o', -25),
+    ('K', 'u', -15),
+    ('K', 'y', -45),
+    ('L', 'T', -92),
+    ('L', 'V', -92),
+    ('L', 'W', -92),
+    ('L', 'Y', -92),
+    ('L', 'y', -55),
+    ('L', '\u{2019}', -110),
+    ('L', '\u{201d}', -20),
+    ('N', 'A', -20),
+    ('O', 'A', -40),
+    ('O', 'T', -40),
+    ('O', 'V', -50),
+    ('O', 'W', -50),
+    ('O', 'X', -40),
+    ('O', 'Y', -50),
+    ('P', ',', -92),
+    ('P', '.', -110),
+    ('P', 'A', -74),
+    ('P', 'a', -10),
+    ('P', 'e', -20),
+    ('P', 'o', -20),
+    ('Q', '.', -20),
+    ('Q', 'U', -10),
+    ('R', 'O', -30),
+    ('R', 'T', -40),
+    ('R', 'U', -30),
+    ('R', 'V', -55),
+    ('R', 'W', -35),
+    ('R', 'Y', -35),
+    ('T', ',', -74),
+    ('T', '-', -92),
+    ('T', '.', -90),
+    ('T', ':', -74),
+    ('T', ';', -74),
+    ('T', 'A', -90),
+    ('T', 'O', -18),
+    ('T', 'a', -92),
+    ('T', 'e', -92),
+    ('T', 'i', -18),
+    ('T', 'o', -92),
+    ('T', 'r', -74),
+    ('T', 'u', -92),
+    ('T', 'w', -74),
+    ('T', 'y', -74),
+    ('U', ',', -50),
+    ('U', '.', -50),
+    ('U', 'A', -60),
+    ('V', ',', -129),
+    ('V', '-', -74),
+    ('V', '.', -145),
+    ('V', ':', -92),
+    ('V', ';', -92),
+    ('V', 'A', -135),
+    ('V', 'G', -30),
+    ('V', 'O', -45),
+    ('V', 'a', -92),
+    ('V', 'e', -111),
+    ('V', 'i', -37),
+    ('V', 'o', -111),
+    ('V', 'u', -92),
+    ('W', ',', -92),
+    ('W', '-', -37),
+    ('W', '.', -92),
+    ('W', ':', -55),
+    ('W', ';', -55),
+    ('W', 'A', -120),
+    ('W', 'O', -10),
+    ('W', 'a', -65),
+    ('W', 'e', -65),
+    ('W', 'i', -18),
+    ('W', 'o', -75),
+    ('W', 'u', -50),
+    ('W', 'y', -60),
+    ('Y', ',', -92),
+    ('Y', '-', -92),
+    ('Y', '.', -92),
+    ('Y', ':', -92),
+    ('Y', ';', -92),
+    ('Y', 'A', -110),
+    ('Y', 'O', -35),
+    ('Y', 'a', -85),
+    ('Y', 'e', -111),
+    ('Y', 'i', -37),
+    ('Y', 'o', -111),
+    ('Y', 'u', -92),
+    ('a', 'v', -25),
+    ('b', '.', -40),
+    ('b', 'b', -10),
+    ('b', 'u', -20),
+    ('b', 'v', -15),
+    ('d', 'w', -15),
+    ('e', 'v', -15),
+    ('f', ',', -15),
+    ('f', 'e', -25),
+    ('f', 'o', -25),
+    ('f', '\u{2019}', 55),
+    ('h', 'y', -15),
+    ('i', 'v', -10),
+    ('k', 'e', -10),
+    ('k', 'o', -15),
+    ('k', 'y', -15),
+    ('n', 'v', -40),
+    ('o', 'v', -10),
+    ('o', 'w', -10),
+    ('r', ',', -92),
+    ('r', '-', -37),
+    ('r', '.', -100),
+    ('r', 'c', -18),
+    ('r', 'e', -18),
+    ('r', 'g', -10),
+    ('r', 'o', -18),
+    ('v', ',', -55),
+    ('v', '.', -70),
+    ('v', 'a', -10),
+    ('v', 'e', -10),
+    ('v', 'o', -10),
+    ('w', ',', -55),
+    ('w', '.', -70),
+    ('w', 'o', -10),
+    ('y', ',', -55),
+    ('y', '.', -70),
+    ('y', 'e', -10),
+    ('y', 'o', -25),
+    ('\u{2018}', 'A', -10),
+    ('\u{2018}', '\u{2018}', -63),
+    ('\u{2019}', ' ', -74),
+    ('\u{2019}', 'd', -20),
+    ('\u{2019}', 'r', -20),
+    ('\u{2019}', 's', -37),
+    ('\u{2019}', 't', -30),
+    ('\u{2019}', 'v', -20),
+    ('\u{2019}', '\u{2019}', -63),
+    ('\u{201c}', 'A', -10),
+];
+
+/// Kerning data for Times-Italic, sorted ( first char, second char, adjustment ).
+pub static TIMES_KERN2: [(char, char, i16); 179] = [
+    (' ', 'A', -18),
+    (' ', 'T', -18),
+    (' ', 'V', -35),
+    (' ', 'W', -40),
+    (' ', 'Y', -75),
+    (',', '\u{2019}', -140),
+    (',', '\u{201d}', -140),
+    ('.', '\u{2019}', -140),
+    ('.', '\u{201d}', -140),
+    ('A', 'C', -30),
+    ('A', 'G', -35),
+    ('A', 'O', -40),
+    ('A', 'Q', -40),
+    ('A', 'T', -37),
+    ('A', 'U', -50),
+    ('A', 'V', -105),
+    ('A', 'W', -95),
+    ('A', 'Y', -55),
+    ('A', 'u', -20),
+    ('A', 'v', -55),
+    ('A', 'w', -55),
+    ('A', 'y', -55),
+    ('A', '\u{2019}', -37),
+    ('B', 'A', -25),
+    ('B', 'U', -10),
+    ('D', 'A', -35),
+    ('D', 'V', -40),
+    ('D', 'W', -40),
+    ('D', 'Y', -40),
+    ('F', ',', -135),
+    ('F', '.', -135),
+    ('F', 'A', -115),
+    ('F', 'a', -75),
+    ('F', 'e', -75),
+    ('F', 'i', -45),
+    ('F', 'o', -105),
+    ('F', 'r', -55),
+    ('J', ',', -25),
+    ('J', '.', -25),
+    ('J', 'A', -40),
+    ('J', 'a', -35),
+    ('J', 'e', -25),
+    ('J', 'o', -25),
+    ('J', 'u', -35),
+    ('K', 'O', -50),
+    ('K', 'e', -35),
+    ('K', 'o', -40),
+    ('K', 'u', -40),
+    ('K', 'y', -40),
+    ('L', 'T', -20),
+    ('L', 'V', -55),
+    ('L', 'W', -55),
+    ('L', 'Y', -20),
+    ('L', 'y', -30),
+    ('L', '\u{2019}', -37),
+    ('N', 'A', -27),
+    ('O', 'A', -55),
+    ('O', 'T', -40),
+    ('O', 'V', -50),
+    ('O', 'W', -50),
+    ('O', 'X', -40),
+    ('O', 'Y', -50),
+    ('P', ',', -135),
+    ('P', '.', -135),
+    ('P', 'A', -90),
+    ('P', 'a', -80),
+    ('P', 'e', -80),
+    ('P', 'o', -80),
+    ('Q', 'U', -10),
+    ('R', 'O', -40),
+    ('R', 'U', -40),
+    ('R', 'V', -18),
+    ('R', 'W', -18),
+    ('R', 'Y', -18),
+    ('T', ',', -74),
+    ('T', '-', -74),
+    ('T', '.', -74),
+    ('T', ':', -55),
+    ('T', ';', -65),
+    ('T', 'A', -50),
+    ('T', 'O', -18),
+    ('T', 'a', -92),
+    ('T', 'e', -92),
+    ('T', 'i', -55),
+    ('T', 'o', -92),
+    ('T', 'r', -55),
+    ('T', 'u', -55),
+    ('T', 'w', -74),
+    ('T', 'y', -74),
+    ('U', ',', -25),
+    ('U', '.', -25),
+    ('U', 'A', -40),
+    ('V', ',', -129),
+    ('V', '-', -55),
+    ('V', '.', -129),
+    ('V', ':', -65),
+    ('V', ';', -74),
+    ('V', 'A', -60),
+    ('V', 'O', -30),
+    ('V', 'a', -111),
+    ('V', 'e', -111),
+    ('V', 'i', -74),
+    ('V', 'o', -111),
+    ('V', 'u', -74),
+    ('W', ',', -92),
+    ('W', '-', -37),
+    ('W', '.', -92),
+    ('W', ':', -65),
+    ('W', ';', -65),
+    ('W', 'A', -60),
+    ('W', 'a', -92),
+    ('W', 'e', -92),
+    ('W', 'i', -55),
+    ('W', 'o', -92),
+    ('W', 'u', -55),
+    ('W', 'y', -70),
+    ('Y', ',', -92),
+    ('Y', '-', -74),
+    ('Y', '.', -92),
+    ('Y', ':', -65),
+    ('Y', ';', -65),
+    ('Y', 'A', -50),
+    ('Y', 'O', -15),
+    ('Y', 'a', -92),
+    ('Y', 'e', -92),
+    ('Y', 'i', -74),
+    ('Y', 'o', -92),
+    ('Y', 'u', -92),
+    ('a', 'g', -10),
+    ('b', '.', -40),
+    ('b', 'u', -20),
+    ('c', 'h', -15),
+    ('c', 'k', -20),
+    ('e', ',', -10),
+    ('e', '.', -15),
+    ('e', 'g', -40),
+    ('e', 'v', -15),
+    ('e', 'w', -15),
+    ('e', 'x', -20),
+    ('e', 'y', -30),
+    ('f', ',', -10),
+    ('f', '.', -15),
+    ('f', 'f', -18),
+    ('f', '\u{2019}', 92),
+    ('g', ',', -10),
+    ('g', '.', -15),
+    ('g', 'e', -10),
+    ('g', 'g', -10),
+    ('k', 'e', -10),
+    ('k', 'o', -10),
+    ('k', 'y', -10),
+    ('n', 'v', -40),
+    ('o', 'g', -10),
+    ('o', 'v', -10),
+    ('r', ',', -111),
+    ('r', '-', -20),
+    ('r', '.', -111),
+    ('r', 'a', -15),
+    ('r', 'c', -37),
+    ('r', 'd', -37),
+    ('r', 'e', -37),
+    ('r', 'g', -37),
+    ('r', 'o', -45),
+    ('r', 'q', -37),
+    ('r', 's', -10),
+    ('v', ',', -74),
+    ('v', '.', -74),
+    ('w', ',', -74),
+    ('w', '.', -74),
+    ('y', ',', -55),
+    ('y', '.', -55),
+    ('\u{2018}', '\u{2018}', -111),
+    ('\u{2019}', ' ', -111),
+    ('\u{2019}', 'd', -25),
+    ('\u{2019}', 'r', -25),
+    ('\u{2019}', 's', -40),
+    ('\u{2019}', 't', -30),
+    ('\u{2019}', 'v', -10),
+    ('\u{2019}', '\u{2019}', -111),
+];
+
+/// Kerning data for Times-BoldItalic, sorted ( first char, second char, adjustment ).
+pub static TIMES_KERN3: [(char, char, i16); 166] = [
+    (' ', 'A', -37),
+    (' ', 'V', -70),
+    (' ', 'W', -70),
+    (' ', 'Y', -70),
+    (',', '\u{2019}', -95),
+    (',', '\u{201d}', -95),
+    ('.', '\u{2019}', -95),
+    ('.', '\u{201d}', -95),
+    ('A', 'C', -65),
+    ('A', 'G', -60),
+    ('A', 'O', -50),
+    ('A', 'Q', -55),
+    ('A', 'T', -55),
+    ('A', 'U', -50),
+    ('A', 'V', -100),
+    ('A', 'W', -100),
+    ('A', 'Y', -70),
+    ('A', 'u', -30),
+    ('A', 'v', -74),
+    ('A', 'w', -74),
+    ('A', 'y', -74),
+    ('A', '\u{2019}', -74),
+    ('B', 'A', -25),
+    ('B', 'U', -10),
+    ('D', 'A', -25),
+    ('D', 'V', -50),
+    ('D', 'W', -40),
+    ('D', 'Y', -50),
+    ('F', ',', -129),
+    ('F', '.', -129),
+    ('F', 'A', -100),
+    ('F', 'a', -95),
+    ('F', 'e', -100),
+    ('F', 'i', -40),
+    ('F', 'o', -70),
+    ('F', 'r', -50),
+    ('J', ',', -10),
+    ('J', '.', -10),
+    ('J', 'A', -25),
+    ('J', 'a', -40),
+    ('J', 'e', -40),
+    ('J', 'o', -40),
+    ('J', 'u', -40),
+    ('K', 'O', -30),
+    ('K', 'e', -25),
+    ('K', 'o', -25),
+    ('K', 'u', -20),
+    ('K', 'y', -20),
+    ('L', 'T', -18),
+    ('L', 'V', -37),
+    ('L', 'W', -37),
+    ('L', 'Y', -37),
+    ('L', 'y', -37),
+    ('L', '\u{2019}', -55),
+    ('N', 'A', -30),
+    ('O', 'A', -40),
+    ('O', 'T', -40),
+    ('O', 'V', -50),
+    ('O', 'W', -50),
+    ('O', 'X', -40),
+    ('O', 'Y', -50),
+    ('P', ',', -129),
+    ('P', '.', -129),
+    ('P', 'A', -85),
+    ('P', 'a', -40),
+    ('P', 'e', -50),
+    ('P', 'o', -55),
+    ('Q', 'U', -10),
+    ('R', 'O', -50),
+    ('R', 'T', -30),
+    ('R', 'U', -40),
+    ('R', 'V', -18),
+    ('R', 'W', -18),
+    ('R', 'Y', -18),
+    ('T', ',', -92),
+    ('T', '-', -92),
+    ('T', '.', -92),
+    ('T', ':', -74),
+    ('T', ';', -74),
+    ('T', 'A', -55),
+    ('T', 'O', -18),
+    ('T', 'a', -92),
+    ('T', 'e', -92),
+    ('T', 'h', -37),
+    ('T', 'i', -37),
+    ('T', 'o', -95),
+    ('T', 'r', -37),
+    ('T', 'u', -37),
+    ('T', 'w', -37),
+    ('T', 'y', -37),
+    ('U', 'A', -45),
+    ('V', ',', -129),
+    ('V', '-', -70),
+    ('V', '.', -129),
+    ('V', ':', -74),
+    ('V', ';', -74),
+    ('V', 'A', -85),
+    ('V', 'G', -10),
+    ('V', 'O', -30),
+    ('V', 'a', -111),
+    ('V', 'e', -111),
+    ('V', 'i', -55),
+    ('V', 'o', -111),
+    ('V', 'u', -70),
+    ('W', ',', -74),
+    ('W', '-', -50),
+    ('W', '.', -74),
+    ('W', ':', -55),
+    ('W', ';', -55),
+    ('W', 'A', -74),
+    ('W', 'O', -15),
+    ('W', 'a', -85),
+    ('W', 'e', -90),
+    ('W', 'i', -37),
+    ('W', 'o', -80),
+    ('W', 'u', -55),
+    ('W', 'y', -55),
+    ('Y', ',', -92),
+    ('Y', '-', -92),
+    ('Y', '.', -74),
+    ('Y', ':', -92),
+    ('Y', ';', -92),
+    ('Y', 'A', -74),
+    ('Y', 'O', -25),
+    ('Y', 'a', -92),
+    ('Y', 'e', -111),
+    ('Y', 'i', -55),
+    ('Y', 'o', -111),
+    ('Y', 'u', -92),
+    ('b', '.', -40),
+    ('b', 'b', -10),
+    ('b', 'u', -20),
+    ('c', 'h', -10),
+    ('c', 'k', -10),
+    ('e', 'b', -10),
+    ('f', ',', -10),
+    ('f', '.', -10),
+    ('f', 'e', -10),
+    ('f', 'f', -18),
+    ('f', 'o', -10),
+    ('f', '\u{2019}', 55),
+    ('k', 'e', -30),
+    ('k', 'o', -10),
+    ('n', 'v', -40),
+    ('o', 'v', -15),
+    ('r', ',', -65),
+    ('r', '.', -65),
+    ('v', ',', -37),
+    ('v', '.', -37),
+    ('v', 'e', -30),
+    ('v', 'o', -15),
+    ('w', ',', -37),
+    ('w', '.', -37),
+    ('w', 'e', -10),
+    ('w', 'o', -15),
+    ('x', 'e', -10),
+    ('y', ',', -37),
+    ('y', '.', -37),
+    ('\u{2018}', '\u{2018}', -74),
+    ('\u{2019}', ' ', -74),
+    ('\u{2019}', 'd', -15),
+    ('\u{2019}', 'r', -15),
+    ('\u{2019}', 's', -74),
+    ('\u{2019}', 't', -37),
+    ('\u{2019}', 'v', -15),
+    ('\u{2019}', '\u{2019}', -74),
+];
+
+/// Metrics for Courier, Courier-Bold, Courier-Oblique and Courier-BoldOblique.
+pub static COURIER_METRICS: [FontMetrics; 4] = [
+    FontMetrics {
+        ascent: 629,
+        descent: -157,
+        cap_height: 562,
+        x_height: 426,
+        bbox: [-23, -250, 715, 805],
+    },
+    FontMetrics {
+        ascent: 629,
+        descent: -157,
+        cap_height: 562,
+        x_height: 439,
+        bbox: [-113, -250, 749, 801],
+    },
+    FontMetrics {
+        ascent: 629,
+        descent: -157,
+        cap_height: 562,
+        x_height: 426,
+        bbox: [-27, -250, 849, 805],
+    },
+    FontMetrics {
+        ascent: 629,
+        descent: -157,
+        cap_height: 562,
+        x_height: 439,
+        bbox: [-57, -250, 869, 801],
+    },
+];
+
+/// Metrics for Times-Roman, Times-Bold, Times-Italic and Times-BoldItalic.
+pub static TIMES_METRICS: [FontMetrics; 4] = [
+    FontMetrics {
+        ascent: 683,
+        descent: -217,
+        cap_height: 662,
+        x_height: 450,
+        bbox: [-168, -218, 1000, 898],
+    },
+    FontMetrics {
+        ascent: 683,
+        descent: -217,
+        cap_height: 676,
+        x_height: 461,
+        bbox: [-168, -218, 1000, 935],
+    },
+    FontMetrics {
+        ascent: 683,
+        descent: -217,
+        cap_height: 653,
+        x_height: 441,
+        bbox: [-169, -217, 1010, 883],
+    },
+    FontMetrics {
+        ascent: 683,
+        descent: -217,
+        cap_height: 669,
+        x_height: 462,
+        bbox: [-200, -218, 996, 921],
+    },
+];
+
+/// Metrics for Helvetica, Helvetica-Bold, Helvetica-Oblique and Helvetica-BoldOblique.
+pub static HELVETICA_METRICS: [FontMetrics; 4] = [
+    FontMetrics {
+        ascent: 718,
+        descent: -207,
+        cap_height: 718,
+        x_height: 523,
+        bbox: [-166, -225, 1000, 931],
+    },
+    FontMetrics {
+        ascent: 718,
+        descent: -207,
+        cap_height: 718,
+        x_height: 532,
+        bbox: [-170, -228, 1003, 962],
+    },
+    FontMetrics {
+        ascent: 718,
+        descent: -207,
+        cap_height: 718,
+        x_height: 523,
+        bbox: [-170, -225, 1116, 931],
+    },
+    FontMetrics {
+        ascent: 718,
+        descent: -207,
+        cap_height: 718,
+        x_height: 532,
+        bbox: [-174, -228, 1114, 962],
+    },
+];
+
+/// Built-in encoding of Symbol, sorted ( Unicode character, code ).
+pub static SYMBOL_MAP: [(char, u8); 190] = [
+    (' ', 32),
+    ('!', 33),
+    ('#', 35),
+    ('%', 37),
+    ('&', 38),
+    ('(', 40),
+    (')', 41),
+    ('+', 43),
+    (',', 44),
+    ('.', 46),
+    ('/', 47),
+    ('0', 48),
+    ('1', 49),
+    ('2', 50),
+    ('3', 51),
+    ('4', 52),
+    ('5', 53),
+    ('6', 54),
+    ('7', 55),
+    ('8', 56),
+    ('9', 57),
+    (':', 58),
+    (';', 59),
+    ('<', 60),
+    ('=', 61),
+    ('>', 62),
+    ('?', 63),
+    ('[', 91),
+    (']', 93),
+    ('_', 95),
+    ('{', 123),
+    ('|', 124),
+    ('}', 125),
+    ('\u{a9}', 227),
+    ('\u{ac}', 216),
+    ('\u{ae}', 226),
+    ('\u{b0}', 176),
+    ('\u{b1}', 177),
+    ('\u{b5}', 109),
+    ('\u{d7}', 180),
+    ('\u{f7}', 184),
+    ('\u{192}', 166),
+    ('\u{391}', 65),
+    ('\u{392}', 66),
+    ('\u{393}', 71),
+    ('\u{394}', 68),
+    ('\u{395}', 69),
+    ('\u{396}', 90),
+    ('\u{397}', 72),
+    ('\u{398}', 81),
+    ('\u{399}', 73),
+    ('\u{39a}', 75),
+    ('\u{39b}', 76),
+    ('\u{39c}', 77),
+    ('\u{39d}', 78),
+    ('\u{39e}', 88),
+    ('\u{39f}', 79),
+    ('\u{3a0}', 80),
+    ('\u{3a1}', 82),
+    ('\u{3a3}', 83),
+    ('\u{3a4}', 84),
+    ('\u{3a5}', 85),
+    ('\u{3a6}', 70),
+    ('\u{3a7}', 67),
+    ('\u{3a8}', 89),
+    ('\u{3a9}', 87),
+    ('\u{3b1}', 97),
+    ('\u{3b2}', 98),
+    ('\u{3b3}', 103),
+    ('\u{3b4}', 100),
+    ('\u{3b5}', 101),
+    ('\u{3b6}', 122),
+    ('\u{3b7}', 104),
+    ('\u{3b8}', 113),
+    ('\u{3b9}', 105),
+    ('\u{3ba}', 107),
+    ('\u{3bb}', 108),
+    ('\u{3bc}', 109),
+    ('\u{3bd}', 110),
+    ('\u{3be}', 120),
+    ('\u{3bf}', 111),
+    ('\u{3c0}', 112),
+    ('\u{3c1}', 114),
+    ('\u{3c2}', 86),
+    ('\u{3c3}', 115),
+    ('\u{3c4}', 116),
+    ('\u{3c5}', 117),
+    ('\u{3c6}', 102),
+    ('\u{3c7}', 99),
+    ('\u{3c8}', 121),
+    ('\u{3c9}', 119),
+    ('\u{3d1}', 74),
+    ('\u{3d2}', 161),
+    ('\u{3d5}', 106),
+    ('\u{3d6}', 118),
+    ('\u{2022}', 183),
+    ('\u{2026}', 188),
+    ('\u{2032}', 162),
+    ('\u{2033}', 178),
+    ('\u{2044}', 164),
+    ('\u{20ac}', 160),
+    ('\u{2111}', 193),
+    ('\u{2118}', 195),
+    ('\u{211c}', 194),
+    ('\u{2122}', 228),
+    ('\u{2126}', 87),
+    ('\u{2135}', 192),
+    ('\u{2190}', 172),
+    ('\u{2191}', 173),
+    ('\u{2192}', 174),
+    ('\u{2193}', 175),
+    ('\u{2194}', 171),
+    ('\u{21b5}', 191),
+    ('\u{21d0}', 220),
+    ('\u{21d1}', 221),
+    ('\u{21d2}', 222),
+    ('\u{21d3}', 223),
+    ('\u{21d4}', 219),
+    ('\u{2200}', 34),
+    ('\u{2202}', 182),
+    ('\u{2203}', 36),
+    ('\u{2205}', 198),
+    ('\u{2206}', 68),
+    ('\u{2207}', 209),
+    ('\u{2208}', 206),
+    ('\u{2209}', 207),
+    ('\u{220b}', 39),
+    ('\u{220f}', 213),
+    ('\u{2211}', 229),
+    ('\u{2212}', 45),
+    ('\u{2217}', 42),
+    ('\u{221a}', 214),
+    ('\u{221d}', 181),
+    ('\u{221e}', 165),
+    ('\u{2220}', 208),
+    ('\u{2227}', 217),
+    ('\u{2228}', 218),
+    ('\u{2229}', 199),
+    ('\u{222a}', 200),
+    ('\u{222b}', 242),
+    ('\u{2234}', 92),
+    ('\u{223c}', 126),
+    ('\u{2245}', 64),
+    ('\u{2248}', 187),
+    ('\u{2260}', 185),
+    ('\u{2261}', 186),
+    ('\u{2264}', 163),
+    ('\u{2265}', 179),
+    ('\u{2282}', 204),
+    ('\u{2283}', 201),
+    ('\u{2284}', 203),
+    ('\u{2286}', 205),
+    ('\u{2287}', 202),
+    ('\u{2295}', 197),
+    ('\u{2297}', 196),
+    ('\u{22a5}', 94),
+    ('\u{22c5}', 215),
+    ('\u{2320}', 243),
+    ('\u{2321}', 245),
+    ('\u{2329}', 225),
+    ('\u{232a}', 241),
+    ('\u{239b}', 230),
+    ('\u{239c}', 231),
+    ('\u{239d}', 232),
+    ('\u{239e}', 246),
+    ('\u{239f}', 247),
+    ('\u{23a0}', 248),
+    ('\u{23a1}', 233),
+    ('\u{23a2}', 234),
+    ('\u{23a3}', 235),
+    ('\u{23a4}', 249),
+    ('\u{23a5}', 250),
+    ('\u{23a6}', 251),
+    ('\u{23a7}', 236),
+    ('\u{23a8}', 237),
+    ('\u{23a9}', 238),
+    ('\u{23aa}', 239),
+    ('\u{23ab}', 252),
+    ('\u{23ac}', 253),
+    ('\u{23ad}', 254),
+    ('\u{23ae}', 244),
+    ('\u{23af}', 190),
+    ('\u{23d0}', 189),
+    ('\u{25ca}', 224),
+    ('\u{2660}', 170),
+    ('\u{2663}', 167),
+    ('\u{2665}', 169),
+    ('\u{2666}', 168),
+    ('\u{3008}', 225),
+    ('\u{3009}', 241),
+];
+
+/// Width data
+pub static ZAPF_DINGBATS: [u16; 224] = [
+    /* C 32 */ 278,
+    /* C 33 */ 974,
+    /* C 34 */ 961,
+    /* C 35 */ 974,
+    /* C 36 */ 980,
+    /* C 37 */ 719,
+    /* C 38 */ 789,
+    /* C 39 */ 790,
+    /* C 40 */ 791,
+    /* C 41 */ 690,
+    /* C 42 */ 960,
+    /* C 43 */ 939,
+    /* C 44 */ 549,
+    /* C 45 */ 855,
+    /* C 46 */ 911,
+    /* C 47 */ 933,
+    /* C 48 */ 911,
+    /* C 49 */ 945,
+    /* C 50 */ 974,
+    /* C 51 */ 755,
+    /* C 52 */ 846,
+    /* C 53 */ 762,
+    /* C 54 */ 761,
+    /* C 55 */ 571,
+    /* C 56 */ 677,
+    /* C 57 */ 763,
+    /* C 58 */ 760,
+    /* C 59 */ 759,
+    /* C 60 */ 754,
+    /* C 61 */ 494,
+    /* C 62 */ 552,
+    /* C 63 */ 537,
+    /* C 64 */ 577,
+    /* C 65 */ 692,
+    /* C 66 */ 786,
+    /* C 67 */ 788,
+    /* C 68 */ 788,
+    /* C 69 */ 790,
+    /* C 70 */ 793,
+    /* C 71 */ 794,
+    /* C 72 */ 816,
+    /* C 73 */ 823,
+    /* C 74 */ 789,
+    /* C 75 */ 841,
+    /* C 76 */ 823,
+    /* C 77 */ 833,
+    /* C 78 */ 816,
+    /* C 79 */ 831,
+    /* C 80 */ 923,
+    /* C 81 */ 744,
+    /* C 82 */ 723,
+    /* C 83 */ 749,
+    /* C 84 */ 790,
+    /* C 85 */ 792,
+    /* C 86 */ 695,
+    /* C 87 */ 776,
+    /* C 88 */ 768,
+    /* C 89 */ 792,
+    /* C 90 */ 759,
+    /* C 91 */ 707,
+    /* C 92 */ 708,
+    /* C 93 */ 682,
+    /* C 94 */ 701,
+    /* C 95 */ 826,
+    /* C 96 */ 815,
+    /* C 97 */ 789,
+    /* C 98 */ 789,
+    /* C 99 */ 707,
+    /* C 100 */ 687,
+    /* C 101 */ 696,
+    /* C 102 */ 689,
+    /* C 103 */ 786,
+    /* C 104 */ 787,
+    /* C 105 */ 713,
+    /* C 106 */ 791,
+    /* C 107 */ 785,
+    /* C 108 */ 791,
+    /* C 109 */ 873,
+    /* C 110 */ 761,
+    /* C 111 */ 762,
+    /* C 112 */ 762,
+    /* C 113 */ 759,
+    /* C 114 */ 759,
+    /* C 115 */ 892,
+    /* C 116 */ 892,
+    /* C 117 */ 788,
+    /* C 118 */ 784,
+    /* C 119 */ 438,
+    /* C 120 */ 138,
+    /* C 121 */ 277,
+    /* C 122 */ 415,
+    /* C 123 */ 392,
+    /* C 124 */ 392,
+    /* C 125 */ 668,
+    /* C 126 */ 668,
+    500,
+    /* C 128 */ 390,
+    /* C 129 */ 390,
+    /* C 130 */ 317,
+    /* C 131 */ 317,
+    /* C 132 */ 276,
+    /* C 133 */ 276,
+    /* C 134 */ 509,
+    /* C 135 */ 509,
+    /* C 136 */ 410,
+    /* C 137 */ 410,
+    /* C 138 */ 234,
+    /* C 139 */ 234,
+    /* C 140 */ 334,
+    /* C 141 */ 334,
+    500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 500,
+    /* C 161 */ 732,
+    /* C 162 */ 544,
+    /* C 163 */ 544,
+    /* C 164 */ 910,
+    /* C 165 */ 667,
+    /* C 166 */ 760,
+    /* C 167 */ 760,
+    /* C 168 */ 776,
+    /* C 169 */ 595,
+    /* C 170 */ 694,
+    /* C 171 */ 626,
+    /* C 172 */ 788,
+    /* C 173 */ 788,
+    /* C 174 */ 788,
+    /* C 175 */ 788,
+    /* C 176 */ 788,
+    /* C 177 */ 788,
+    /* C 178 */ 788,
+    /* C 179 */ 788,
+    /* C 180 */ 788,
+    /* C 181 */ 788,
+    /* C 182 */ 788,
+    /* C 183 */ 788,
+    /* C 184 */ 788,
+    /* C 185 */ 788,
+    /* C 186 */ 788,
+    /* C 187 */ 788,
+    /* C 188 */ 788,
+    /* C 189 */ 788,
+    /* C 190 */ 788,
+    /* C 191 */ 788,
+    /* C 192 */ 788,
+    /* C 193 */ 788,
+    /* C 194 */ 788,
+    /* C 195 */ 788,
+    /* C 196 */ 788,
+    /* C 197 */ 788,
+    /* C 198 */ 788,
+    /* C 199 */ 788,
+    /* C 200 */ 788,
+    /* C 201 */ 788,
+    /* C 202 */ 788,
+    /* C 203 */ 788,
+    /* C 204 */ 788,
+    /* C 205 */ 788,
+    /* C 206 */ 788,
+    /* C 207 */ 788,
+    /* C 208 */ 788,
+    /* C 209 */ 788,
+    /* C 210 */ 788,
+    /* C 211 */ 788,
+    /* C 212 */ 894,
+    /* C 213 */ 838,
+    /* C 214 */ 1016,
+    /* C 215 */ 458,
+    /* C 216 */ 748,
+    /* C 217 */ 924,
+    /* C 218 */ 748,
+    /* C 219 */ 918,
+    /* C 220 */ 927,
+    /* C 221 */ 928,
+    /* C 222 */ 928,
+    /* C 223 */ 834,
+    /* C 224 */ 873,
+    /* C 225 */ 828,
+    /* C 226 */ 924,
+    /* C 227 */ 924,
+    /* C 228 */ 917,
+    /* C 229 */ 930,
+    /* C 230 */ 931,
+    /* C 231 */ 463,
+    /* C 232 */ 883,
+    /* C 233 */ 836,
+    /* C 234 */ 836,
+    /* C 235 */ 867,
+    /* C 236 */ 867,
+    /* C 237 */ 696,
+    /* C 238 */ 696,
+    /* C 239 */ 874,
+    500,
+    /* C 241 */ 874,
+    /* C 242 */ 760,
+    /* C 243 */ 946,
+    /* C 244 */ 771,
+    /* C 245 */ 865,
+    /* C 246 */ 771,
+    /* C 247 */ 888,
+    /* C 248 */ 967,
+    /* C 249 */ 888,
+    /* C 250 */ 831,
+    /* C 251 */ 873,
+    /* C 252 */ 927,
+    /* C 253 */ 970,
+    /* C 254 */ 918,
+    500,
+];
+
+/// Built-in encoding of ZapfDingbats, sorted ( Unicode character, code ).
+pub static ZAPF_DINGBATS_MAP: [(char, u8); 202] = [
+    (' ', 32),
+    ('\u{2192}', 213),
+    ('\u{2194}', 214),
+    ('\u{2195}', 215),
+    ('\u{2460}', 172),
+    ('\u{2461}', 173),
+    ('\u{2462}', 174),
+    ('\u{2463}', 175),
+    ('\u{2464}', 176),
+    ('\u{2465}', 177),
+    ('\u{2466}', 178),
+    ('\u{2467}', 179),
+    ('\u{2468}', 180),
+    ('\u{2469}', 181),
+    ('\u{25a0}', 110),
+    ('\u{25b2}', 115),
+    ('\u{25bc}', 116),
+    ('\u{25c6}', 117),
+    ('\u{25cf}', 108),
+    ('\u{25d7}', 119),
+    ('\u{2605}', 72),
+    ('\u{260e}', 37),
+    ('\u{261b}', 42),
+    ('\u{261e}', 43),
+    ('\u{2660}', 171),
+    ('\u{2663}', 168),
+    ('\u{2665}', 170),
+    ('\u{2666}', 169),
+    ('\u{2701}', 33),
+    ('\u{2702}', 34),
+    ('\u{2703}', 35),
+    ('\u{2704}', 36),
+    ('\u{2706}', 38),
+    ('\u{2707}', 39),
+    ('\u{2708}', 40),
+    ('\u{2709}', 41),
+    ('\u{270c}', 44),
+    ('\u{270d}', 45),
+    ('\u{270e}', 46),
+    ('\u{270f}', 47),
+    ('\u{2710}', 48),
+    ('\u{2711}', 49),
+    ('\u{2712}', 50),
+    ('\u{2713}', 51),
+    ('\u{2714}', 52),
+    ('\u{2715}', 53),
+    ('\u{2716}', 54),
+    ('\u{2717}', 55),
+    ('\u{2718}', 56),
+    ('\u{2719}', 57),
+    ('\u{271a}', 58),
+    ('\u{271b}', 59),
+    ('\u{271c}', 60),
+    ('\u{271d}', 61),
+    ('\u{271e}', 62),
+    ('\u{271f}', 63),
+    ('\u{2720}', 64),
+    ('\u{2721}', 65),
+    ('\u{2722}', 66),
+    ('\u{2723}', 67),
+    ('\u{2724}', 68),
+    ('\u{2725}', 69),
+    ('\u{2726}', 70),
+    ('\u{2727}', 71),
+    ('\u{2729}', 73),
+    ('\u{272a}', 74),
+    ('\u{272b}', 75),
+    ('\u{272c}', 76),
+    ('\u{272d}', 77),
+    ('\u{272e}', 78),
+    ('\u{272f}', 79),
+    ('\u{2730}', 80),
+    ('\u{2731}', 81),
+    ('\u{2732}', 82),
+    ('\u{2733}', 83),
+    ('\u{2734}', 84),
+    ('\u{2735}', 85),
+    ('\u{2736}', 86),
+    ('\u{2737}', 87),
+    ('\u{2738}', 88),
+    ('\u{2739}', 89),
+    ('\u{273a}', 90),
+    ('\u{273b}', 91),
+    ('\u{273c}', 92),
+    ('\u{273d}', 93),
+    ('\u{273e}', 94),
+    ('\u{273f}', 95),
+    ('\u{2740}', 96),
+    ('\u{2741}', 97),
+    ('\u{2742}', 98),
+    ('\u{2743}', 99),
+    ('\u{2744}', 100),
+    ('\u{2745}', 101),
+    ('\u{2746}', 102),
+    ('\u{2747}', 103),
+    ('\u{2748}', 104),
+    ('\u{2749}', 105),
+    ('\u{274a}', 106),
+    ('\u{274b}', 107),
+    ('\u{274d}', 109),
+    ('\u{274f}', 111),
+    ('\u{2750}', 112),
+    ('\u{2751}', 113),
+    ('\u{2752}', 114),
+    ('\u{2756}', 118),
+    ('\u{2758}', 120),
+    ('\u{2759}', 121),
+    ('\u{275a}', 122),
+    ('\u{275b}', 123),
+    ('\u{275c}', 124),
+    ('\u{275d}', 125),
+    ('\u{275e}', 126),
+    ('\u{2761}', 161),
+    ('\u{2762}', 162),
+    ('\u{2763}', 163),
+    ('\u{2764}', 164),
+    ('\u{2765}', 165),
+    ('\u{2766}', 166),
+    ('\u{2767}', 167),
+    ('\u{2768}', 128),
+    ('\u{2769}', 129),
+    ('\u{276a}', 130),
+    ('\u{276b}', 131),
+    ('\u{276c}', 132),
+    ('\u{276d}', 133),
+    ('\u{276e}', 134),
+    ('\u{276f}', 135),
+    ('\u{2770}', 136),
+    ('\u{2771}', 137),
+    ('\u{2772}', 138),
+    ('\u{2773}', 139),
+    ('\u{2774}', 140),
+    ('\u{2775}', 141),
+    ('\u{2776}', 182),
+    ('\u{2777}', 183),
+    ('\u{2778}', 184),
+    ('\u{2779}', 185),
+    ('\u{277a}', 186),
+    ('\u{277b}', 187),
+    ('\u{277c}', 188),
+    ('\u{277d}', 189),
+    ('\u{277e}', 190),
+    ('\u{277f}', 191),
+    ('\u{2780}', 192),
+    ('\u{2781}', 193),
+    ('\u{2782}', 194),
+    ('\u{2783}', 195),
+    ('\u{2784}', 196),
+    ('\u{2785}', 197),
+    ('\u{2786}', 198),
+    ('\u{2787}', 199),
+    ('\u{2788}', 200),
+    ('\u{2789}', 201),
+    ('\u{278a}', 202),
+    ('\u{278b}', 203),
+    ('\u{278c}', 204),
+    ('\u{278d}', 205),
+    ('\u{278e}', 206),
+    ('\u{278f}', 207),
+    ('\u{2790}', 208),
+    ('\u{2791}', 209),
+    ('\u{2792}', 210),
+    ('\u{2793}', 211),
+    ('\u{2794}', 212),
+    ('\u{2798}', 216),
+    ('\u{2799}', 217),
+    ('\u{279a}', 218),
+    ('\u{279b}', 219),
+    ('\u{279c}', 220),
+    ('\u{279d}', 221),
+    ('\u{279e}', 222),
+    ('\u{279f}', 223),
+    ('\u{27a0}', 224),
+    ('\u{27a1}', 225),
+    ('\u{27a2}', 226),
+    ('\u{27a3}', 227),
+    ('\u{27a4}', 228),
+    ('\u{27a5}', 229),
+    ('\u{27a6}', 230),
+    ('\u{27a7}', 231),
+    ('\u{27a8}', 232),
+    ('\u{27a9}', 233),
+    ('\u{27aa}', 234),
+    ('\u{27ab}', 235),
+    ('\u{27ac}', 236),
+    ('\u{27ad}', 237),
+    ('\u{27ae}', 238),
+    ('\u{27af}', 239),
+    ('\u{27b1}', 241),
+    ('\u{27b2}', 242),
+    ('\u{27b3}', 243),
+    ('\u{27b4}', 244),
+    ('\u{27b5}', 245),
+    ('\u{27b6}', 246),
+    ('\u{27b7}', 247),
+    ('\u{27b8}', 248),
+    ('\u{27b9}', 249),
+    ('\u{27ba}', 250),
+    ('\u{27bb}', 251),
+    ('\u{27bc}', 252),
+    ('\u{27bd}', 253),
+    ('\u{27be}', 254),
+];
+
+/// Metrics for Symbol ( the AFM has no ascender or descender, so they are from the bounding box ).
+pub static SYMBOL_METRICS: FontMetrics = FontMetrics {
+    ascent: 1010,
+    descent: -293,
+    cap_height: 673,
+    x_height: 500,
+    bbox: [-180, -293, 1090, 1010],
+};
+
+/// Metrics for ZapfDingbats ( ascender and descender are from the bounding box, there are no letters ).
+pub static ZAPF_DINGBATS_METRICS: FontMetrics = FontMetrics {
+    ascent: 820,
+    descent: -143,
+    cap_height: 0,
+    x_height: 0,
+    bbox: [-1, -143, 981, 820],
+};
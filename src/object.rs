@@ -0,0 +1,332 @@
+//!# Object model example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::object::*;
+//!    let mut w = Writer::default();
+//!    html(&mut w, b"<p>Hello").unwrap();
+//!    // A custom object, referenced from the catalog.
+//!    let d = Dict::new()
+//!        .with("Type", Object::name("Example Data"))
+//!        .with("Note", Object::text("Price (€)"))
+//!        .with("Scale", 0.1f32)
+//!        .with("Sizes", vec![Object::Int(1), Object::Real(2.5), Object::Null]);
+//!    let obj = w.b.add_obj(&Object::Dict(d));
+//!    w.b.catalog.set("PieceInfo", Object::Ref(obj));
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(27).any(|x| x == b"/Type/Example#20Data/Note<F"));
+//!    assert!(bytes.windows(28).any(|x| x == b"/Scale 0.1/Sizes[1 2.5 null]"));
+//!
+//!    // Content stream operations.
+//!    let mut c = Vec::new();
+//!    op(&[Object::name("F1"), 12.into()], "Tf", &mut c);
+//!    op(&[Object::text("Hi (1)")], "Tj", &mut c);
+//!    assert_eq!(c, b"/F1 12 Tf (Hi \\(1\\)) Tj");
+//! ```
+
+use crate::*;
+
+/// PDF object.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Object {
+    /// null
+    Null,
+    /// true or false
+    Bool(bool),
+    /// Integer
+    Int(i64),
+    /// Real number ( written with at most 5 decimal places, without an exponent ).
+    Real(f64),
+    /// Name ( without the leading slash, escaped when written ).
+    Name(String),
+    /// String ( bytes, escaped when written ), see Object::text for text strings.
+    String(Vec<u8>),
+    /// Array
+    Array(Vec<Object>),
+    /// Dictionary
+    Dict(Dict),
+    /// Stream, dictionary ( /Length is added ) and data. Must be an indirect object ( see BasicPdfWriter::write_obj ).
+    Stream(Dict, Vec<u8>),
+    /// Reference to an indirect object ( obj number ).
+    Ref(usize),
+}
+
+/// PDF dictionary, entries are written in order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Dict(pub Vec<(String, Object)>);
+
+impl Dict {
+    /// New empty dictionary.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Dictionary with entry added ( for building a dictionary in one expression ).
+    pub fn with(mut self, key: &str, value: impl Into<Object>) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    /// Set entry, replacing any existing entry with the same key.
+    pub fn set(&mut self, key: &str, value: impl Into<Object>) {
+        let value = value.into();
+        match self.0.iter_mut().find(|(k, _)| k == key) {
+            Some(e) => e.1 = value,
+            None => self.0.push((key.to_string(), value)),
+        }
+    }
+
+    /// Get entry.
+    pub fn get(&self, key: &str) -> Option<&Object> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Is the dictionary empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Append dictionary entries ( without the enclosing &lt;&lt; &gt;&gt; ).
+    pub fn write_entries(&self, to: &mut Vec<u8>) {
+        self.write_entries_with(to, &mut string);
+    }
+
+    /// Append dictionary entries, strings are appended by f ( see Object::write_with ).
+    pub fn write_entries_with(&self, to: &mut Vec<u8>, f: &mut dyn FnMut(&[u8], &mut Vec<u8>)) {
+        for (k, v) in &self.0 {
+            pdf_name(k, to);
+            v.write_with(to, f);
+        }
+    }
+
+    /// Append dictionary.
+    pub fn write(&self, to: &mut Vec<u8>) {
+        to.extend_from_slice(b"<<");
+        self.write_entries(to);
+        to.extend_from_slice(b">>");
+    }
+}
+
+impl Object {
+    /// Name object.
+    pub fn name(s: &str) -> Self {
+        Object::Name(s.to_string())
+    }
+
+    /// Text string ( ASCII as is, otherwise UTF-16BE ).
+    pub fn text(s: &str) -> Self {
+        if s.bytes().all(printable) {
+            Object::String(s.as_bytes().to_vec())
+        } else {
+            let mut u = vec![0xfe, 0xff];
+            for c in s.encode_utf16() {
+                u.extend_from_slice(&c.to_be_bytes());
+            }
+            Object::String(u)
+        }
+    }
+
+    /// Reference array, e.g. [1 0 R 2 0 R].
+    pub fn refs(objs: &[usize]) -> Self {
+        Object::Array(objs.iter().map(|x| Object::Ref(*x)).collect())
+    }
+
+    /// Rectangle or other array of numbers.
+    pub fn nums(x: &[f32]) -> Self {
+        Object::Array(x.iter().map(|x| Object::from(*x)).collect())
+    }
+
+    /// Append object.
+    pub fn write(&self, to: &mut Vec<u8>) {
+        self.write_with(to, &mut string);
+    }
+
+    /// Append object, strings are appended by f ( e.g. to encrypt them, see BasicPdfWriter::object ).
+    pub fn write_with(&self, to: &mut Vec<u8>, f: &mut dyn FnMut(&[u8], &mut Vec<u8>)) {
+        match self {
+            Object::Null => token(b"null", to),
+            Object::Bool(b) => token(if *b { b"true" } else { b"false" }, to),
+            Object::Int(x) => token(x.to_string().as_bytes(), to),
+            Object::Real(x) => token(real(*x).as_bytes(), to),
+            Object::Name(s) => pdf_name(s, to),
+            Object::String(s) => f(s, to),
+            Object::Array(a) => {
+                to.push(b'[');
+                for x in a {
+                    x.write_with(to, f);
+                }
+                to.push(b']');
+            }
+            Object::Dict(d) => {
+                to.extend_from_slice(b"<<");
+                d.write_entries_with(to, f);
+                to.extend_from_slice(b">>");
+            }
+            Object::Stream(d, data) => {
+                to.extend_from_slice(b"<<");
+                d.write_entries_with(to, f);
+                to.extend_from_slice(b"/Length");
+                token(data.len().to_string().as_bytes(), to);
+                to.extend_from_slice(b">>stream\n");
+                to.extend_from_slice(data);
+                to.extend_from_slice(b"\nendstream");
+            }
+            Object::Ref(n) => token(format!("{} 0 R", n).as_bytes(), to),
+        }
+    }
+}
+
+/// Append token, preceded by a space if needed to separate it from the previous token.
+fn token(t: &[u8], to: &mut Vec<u8>) {
+    if let Some(&c) = to.last()
+        && !b" \n\r\t()<>[]{}/%".contains(&c)
+    {
+        to.push(b' ');
+    }
+    to.extend_from_slice(t);
+}
+
+/// Append content stream operation ( operands followed by operator ), separated by a space from any preceding operation.
+pub fn op(operands: &[Object], operator: &str, to: &mut Vec<u8>) {
+    if to.last().is_some_and(|b| !b" \n\r\t".contains(b)) {
+        to.push(b' ');
+    }
+    for o in operands {
+        o.write(to);
+        to.push(b' ');
+    }
+    to.extend_from_slice(operator.as_bytes());
+}
+
+/// Real number as text, e.g. 0.1 is "0.1" and 2.0 is "2" ( at most 5 decimal places, no exponent ).
+pub fn real(x: f64) -> String {
+    let x = if x.is_finite() { x } else { 0.0 };
+    let s = format!("{:.5}", x);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+/// Append PDF string, as a literal string if all the bytes are printable ASCII, otherwise as a hex string.
+/// All strings are written by this ( text strings are converted to bytes by Object::text ).
+pub fn string(s: &[u8], to: &mut Vec<u8>) {
+    if s.iter().copied().all(printable) {
+        to.push(b'(');
+        for b in s {
+            match b {
+                b'(' | b')' | b'\\' => to.extend_from_slice(&[b'\\', *b]),
+                b'\n' => to.extend_from_slice(b"\\n"),
+                b'\r' => to.extend_from_slice(b"\\r"),
+                b'\t' => to.extend_from_slice(b"\\t"),
+                _ => to.push(*b),
+            }
+        }
+        to.push(b')');
+    } else {
+        to.push(b'<');
+        hex(s, to);
+        to.push(b'>');
+    }
+}
+
+/// Append bytes as upper case hex digits.
+pub fn hex(s: &[u8], to: &mut Vec<u8>) {
+    const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+    for b in s {
+        to.extend_from_slice(&[DIGITS[(*b >> 4) as usize], DIGITS[(*b & 15) as usize]]);
+    }
+}
+
+/// Is b printable ASCII ( or a tab or line break ).
+fn printable(b: u8) -> bool {
+    (32..127).contains(&b) || b"\n\r\t".contains(&b)
+}
+
+/// Append PDF name ( with leading '/' ), characters other than regular characters are escaped as #xx.
+pub fn pdf_name(s: &str, to: &mut Vec<u8>) {
+    to.push(b'/');
+    for b in s.bytes() {
+        if b > 32 && b < 127 && !b"#()<>[]{}/%".contains(&b) {
+            to.push(b);
+        } else {
+            to.push(b'#');
+            hex(&[b], to);
+        }
+    }
+}
+
+impl From<bool> for Object {
+    fn from(x: bool) -> Self {
+        Object::Bool(x)
+    }
+}
+
+impl From<i32> for Object {
+    fn from(x: i32) -> Self {
+        Object::Int(x as i64)
+    }
+}
+
+impl From<i64> for Object {
+    fn from(x: i64) -> Self {
+        Object::Int(x)
+    }
+}
+
+impl From<usize> for Object {
+    fn from(x: usize) -> Self {
+        Object::Int(x as i64)
+    }
+}
+
+impl From<f32> for Object {
+    fn from(x: f32) -> Self {
+        // Via the shortest decimal form, so 841.89f32 is written as 841.89 rather than 841.89001.
+        Object::Real(x.to_string().parse().unwrap_or(0.0))
+    }
+}
+
+impl From<f64> for Object {
+    fn from(x: f64) -> Self {
+        Object::Real(x)
+    }
+}
+
+impl From<Dict> for Object {
+    fn from(x: Dict) -> Self {
+        Object::Dict(x)
+    }
+}
+
+impl From<Vec<Object>> for Object {
+    fn from(x: Vec<Object>) -> Self {
+        Object::Array(x)
+    }
+}
+
+impl BasicPdfWriter {
+    /// Write o as indirect object obj ( a stream is compressed unless nocomp is set or it already has a /Filter ).
+    pub fn write_obj(&mut self, obj: usize, o: &Object) {
+        if let Object::Stream(d, data) = o {
+            if d.get("Filter").is_some() {
+                self.raw_stream_obj(obj, d, data);
+            } else {
+                self.stream_obj(obj, d, data);
+            }
+        } else {
+            self.start(obj);
+            self.object(o);
+            self.end();
+        }
+    }
+
+    /// Write o as a new indirect object, result is obj number.
+    pub fn add_obj(&mut self, o: &Object) -> usize {
+        let obj = self.obj();
+        self.write_obj(obj, o);
+        obj
+    }
+}
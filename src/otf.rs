@@ -0,0 +1,557 @@
+//!# OpenType font example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::otf::OpenTypeFont;
+//!    // Any OpenType font with CFF outlines will do, the example is skipped if the font is not installed.
+//!    let path = "/usr/share/fonts/opentype/urw-base35/NimbusSans-Regular.otf";
+//!    if let Ok(data) = std::fs::read(path) {
+//!        let mut w = Writer::default();
+//!        w.fonts = [
+//!            Box::new(OpenTypeFont::new(data.clone()).unwrap()),
+//!            Box::new(OpenTypeFont::new(data.clone()).unwrap()),
+//!            Box::new(OpenTypeFont::new(data.clone()).unwrap()),
+//!            Box::new(OpenTypeFont::new(data).unwrap()),
+//!        ];
+//!        html(&mut w, "<p>Zażółć gęślą jaźń.".as_bytes()).unwrap();
+//!        let bytes = w.finish().unwrap();
+//!        assert!(bytes.windows(14).any(|x| x == b"/CIDFontType0/"));
+//!    }
+//! ```
+
+use crate::ttf::{Reader, Sfnt};
+use crate::*;
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+/// OpenType font with CFF outlines ( a .otf file ), embedded as a subset of the glyphs used ( Type0 font with Identity-H encoding ).
+///
+/// The subset is written as a CID-keyed CFF font ( FontFile3 with Subtype CIDFontType0C ), name-keyed fonts are converted.
+/// The font is written by Writer::finish, so it must not be removed from Writer::fonts before then.
+pub struct OpenTypeFont {
+    obj: usize,
+    sfnt: Sfnt,
+    /// Position of the CFF table in the font data.
+    at: Range<usize>,
+    cff: Cff,
+}
+
+/// Parsed CFF table, ranges are relative to the start of the table.
+struct Cff {
+    /// Name INDEX.
+    names: Range<usize>,
+    top: Vec<Entry>,
+    strings: Vec<Range<usize>>,
+    /// Global subroutine INDEX.
+    gsubrs: Range<usize>,
+    charstrings: Vec<Range<usize>>,
+    /// Font DICTs ( a name-keyed font has one, made from the Top DICT ).
+    fds: Vec<FontDict>,
+    /// FDSelect ( CID-keyed fonts only ).
+    fd_select: Option<Range<usize>>,
+}
+
+/// Font DICT with Private DICT and local subroutine INDEX.
+struct FontDict {
+    dict: Vec<Entry>,
+    private: Vec<Entry>,
+    subrs: Option<Range<usize>>,
+}
+
+/// DICT entry.
+struct Entry {
+    /// Operator, two byte operators are 0x0c00 + second byte.
+    op: u16,
+    /// Operands as written.
+    raw: Vec<u8>,
+    /// Operand values ( real numbers are zero ).
+    args: Vec<i64>,
+}
+
+// DICT operators.
+const ROS: u16 = 0x0c1e;
+const CHARSET: u16 = 15;
+const ENCODING: u16 = 16;
+const CHARSTRINGS: u16 = 17;
+const PRIVATE: u16 = 18;
+const SUBRS: u16 = 19;
+const CHARSTRING_TYPE: u16 = 0x0c06;
+const CID_COUNT: u16 = 0x0c22;
+const FD_ARRAY: u16 = 0x0c24;
+const FD_SELECT: u16 = 0x0c25;
+
+impl OpenTypeFont {
+    /// Parse OpenType font data ( a .otf file ). Error if the data is not a valid OpenType font with CFF outlines.
+    pub fn new(data: Vec<u8>) -> Result<Self> {
+        Self::parse(data).ok_or_else(|| Error::Font("not a valid OpenType CFF font".to_string()))
+    }
+
+    fn parse(data: Vec<u8>) -> Option<Self> {
+        let sfnt = Sfnt::parse(data, &[b"OTTO"])?;
+        let (offset, len) = sfnt.table(b"CFF ")?;
+        let at = offset..offset + len;
+        let cff = Cff::parse(&Reader(&sfnt.data[at.clone()]), sfnt.num_glyphs as usize)?;
+        Some(Self {
+            obj: 0,
+            sfnt,
+            at,
+            cff,
+        })
+    }
+
+    /// Font name ( PostScript name from the name table ).
+    pub fn name(&self) -> &str {
+        &self.sfnt.name
+    }
+
+    /// Glyph for character c ( zero if the font has no glyph for c ).
+    pub fn glyph(&self, c: char) -> u16 {
+        self.sfnt.glyph(c)
+    }
+}
+
+impl Font for OpenTypeFont {
+    fn obj(&self) -> usize {
+        self.obj
+    }
+
+    fn width(&self, c: char) -> MPx {
+        self.sfnt.width(c)
+    }
+
+    fn metrics(&self) -> FontMetrics {
+        self.sfnt.metrics()
+    }
+
+    fn init(&mut self, w: &mut BasicPdfWriter) {
+        if self.obj == 0 {
+            self.obj = w.obj();
+        }
+    }
+
+    fn encode(&self, s: &str, to: &mut Vec<u8>) -> Result<()> {
+        self.sfnt.encode(s, to)
+    }
+
+    fn finish(&mut self, w: &mut BasicPdfWriter) {
+        if self.obj == 0 {
+            return;
+        }
+        let mut used = self.sfnt.used.take();
+        used.remove(&0);
+        let mut keep: BTreeSet<u16> = used.keys().copied().collect();
+        keep.insert(0);
+        let base = self.sfnt.subset_name(&keep);
+        let font = self.cff.subset(&self.sfnt.data[self.at.clone()], &keep);
+        let file = w.add_obj(&Object::Stream(
+            Dict::new().with("Subtype", Object::name("CIDFontType0C")),
+            font,
+        ));
+        self.sfnt.write_type0(
+            w,
+            self.obj,
+            &base,
+            &used,
+            ("FontFile3", file),
+            Dict::new().with("Subtype", Object::name("CIDFontType0")),
+        );
+    }
+}
+
+impl Cff {
+    fn parse(r: &Reader, num_glyphs: usize) -> Option<Self> {
+        if r.u8(0)? != 1 {
+            return None;
+        }
+        let start = r.u8(2)? as usize;
+        let (_, end) = index(r, start)?;
+        let names = start..end;
+        let (tops, end) = index(r, end)?;
+        let top = dict(r.0.get(tops.first()?.clone())?)?;
+        let (strings, start) = index(r, end)?;
+        let (_, end) = index(r, start)?;
+        let gsubrs = start..end;
+
+        let arg = |op: u16, i: usize| -> Option<usize> {
+            let e = top.iter().find(|e| e.op == op)?;
+            usize::try_from(*e.args.get(i)?).ok()
+        };
+        if arg(CHARSTRING_TYPE, 0).is_some_and(|t| t != 2) {
+            return None;
+        }
+        let (charstrings, _) = index(r, arg(CHARSTRINGS, 0)?)?;
+        if charstrings.len() != num_glyphs || num_glyphs == 0 {
+            return None;
+        }
+
+        let (fds, fd_select) = if top.iter().any(|e| e.op == ROS) {
+            let (items, _) = index(r, arg(FD_ARRAY, 0)?)?;
+            let mut fds = Vec::new();
+            for x in items {
+                let dict = dict(r.0.get(x)?)?;
+                let (private, subrs) = private(r, dict.iter().find(|e| e.op == PRIVATE)?)?;
+                fds.push(FontDict {
+                    dict,
+                    private,
+                    subrs,
+                });
+            }
+            let at = arg(FD_SELECT, 0)?;
+            let len = match r.u8(at)? {
+                0 => 1 + num_glyphs,
+                3 => 5 + r.u16(at + 1)? as usize * 3,
+                _ => return None,
+            };
+            r.0.get(at..at + len)?;
+            (fds, Some(at..at + len))
+        } else {
+            let (private, subrs) = private(r, top.iter().find(|e| e.op == PRIVATE)?)?;
+            let fd = FontDict {
+                dict: Vec::new(),
+                private,
+                subrs,
+            };
+            (vec![fd], None)
+        };
+        Some(Self {
+            names,
+            top,
+            strings,
+            gsubrs,
+            charstrings,
+            fds,
+            fd_select,
+        })
+    }
+
+    /// CID-keyed CFF with only the glyphs in keep ( others are empty, so glyph numbers are unchanged and CID = glyph number ).
+    /// d is the CFF table.
+    fn subset(&self, d: &[u8], keep: &BTreeSet<u16>) -> Vec<u8> {
+        let n = self.charstrings.len();
+        let mut strings: Vec<&[u8]> = self.strings.iter().map(|x| &d[x.clone()]).collect();
+        // A name-keyed font needs the strings for the CIDSystemInfo ( Registry and Ordering ).
+        let ros = if self.fd_select.is_none() {
+            let sid = 391 + strings.len() as i64;
+            strings.push(b"Adobe");
+            strings.push(b"Identity");
+            Some([sid, sid + 1, 0])
+        } else {
+            None
+        };
+        let strings = write_index(&strings);
+        let gsubrs = &d[self.gsubrs.clone()];
+
+        // Identity charset, glyphs 1 to n-1 are CIDs 1 to n-1.
+        let mut charset = vec![0];
+        if n > 1 {
+            charset = vec![2, 0, 1];
+            charset.extend_from_slice(&(n as u16 - 2).to_be_bytes());
+        }
+        let fd_select = match &self.fd_select {
+            Some(x) => d[x.clone()].to_vec(),
+            None => {
+                let mut s = vec![3, 0, 1, 0, 0, 0];
+                s.extend_from_slice(&(n as u16).to_be_bytes());
+                s
+            }
+        };
+        let charstrings: Vec<&[u8]> = (0..n)
+            .map(|g| match keep.contains(&(g as u16)) {
+                true => &d[self.charstrings[g].clone()],
+                false => &[14][..], // endchar
+            })
+            .collect();
+        let charstrings = write_index(&charstrings);
+
+        // Offsets are written as 5 byte integers, so DICT sizes do not depend on the layout.
+        let top = |o: [usize; 4]| {
+            let mut t = Vec::new();
+            if let Some(ros) = ros {
+                operator(&mut t, &ros, ROS);
+            }
+            for e in &self.top {
+                let skip = [
+                    CHARSET,
+                    ENCODING,
+                    CHARSTRINGS,
+                    PRIVATE,
+                    CID_COUNT,
+                    FD_ARRAY,
+                    FD_SELECT,
+                ];
+                if !skip.contains(&e.op) {
+                    t.extend_from_slice(&e.raw);
+                    op(&mut t, e.op);
+                }
+            }
+            operator(&mut t, &[n as i64], CID_COUNT);
+            operator(&mut t, &[o[0] as i64], CHARSET);
+            operator(&mut t, &[o[1] as i64], FD_SELECT);
+            operator(&mut t, &[o[2] as i64], CHARSTRINGS);
+            operator(&mut t, &[o[3] as i64], FD_ARRAY);
+            write_index(&[t])
+        };
+        let privates: Vec<(usize, Vec<u8>)> = self
+            .fds
+            .iter()
+            .map(|f| {
+                let mut p = Vec::new();
+                for e in f.private.iter().filter(|e| e.op != SUBRS) {
+                    p.extend_from_slice(&e.raw);
+                    op(&mut p, e.op);
+                }
+                let size = p.len() + if f.subrs.is_some() { 6 } else { 0 };
+                if let Some(s) = &f.subrs {
+                    operator(&mut p, &[size as i64], SUBRS);
+                    p.extend_from_slice(&d[s.clone()]);
+                }
+                (size, p)
+            })
+            .collect();
+        let fd_array = |mut at: usize| {
+            let mut fds = Vec::new();
+            for (f, (size, p)) in self.fds.iter().zip(&privates) {
+                let mut t = Vec::new();
+                for e in f.dict.iter().filter(|e| e.op != PRIVATE) {
+                    t.extend_from_slice(&e.raw);
+                    op(&mut t, e.op);
+                }
+                operator(&mut t, &[*size as i64, at as i64], PRIVATE);
+                fds.push(t);
+                at += p.len();
+            }
+            write_index(&fds)
+        };
+
+        let names = &d[self.names.clone()];
+        let mut at = 4 + names.len() + top([0; 4]).len() + strings.len() + gsubrs.len();
+        let mut offsets = [0; 4];
+        for (i, len) in [charset.len(), fd_select.len(), charstrings.len()]
+            .iter()
+            .enumerate()
+        {
+            offsets[i] = at;
+            at += len;
+        }
+        offsets[3] = at;
+        let private_at = at + fd_array(0).len();
+
+        let mut cff = vec![1, 0, 4, 4];
+        for x in [
+            names,
+            &top(offsets),
+            &strings,
+            gsubrs,
+            &charset,
+            &fd_select,
+            &charstrings,
+            &fd_array(private_at),
+        ] {
+            cff.extend_from_slice(x);
+        }
+        for (_, p) in privates {
+            cff.extend_from_slice(&p);
+        }
+        cff
+    }
+}
+
+/// Private DICT and local subroutine INDEX for Private entry e.
+fn private(r: &Reader, e: &Entry) -> Option<(Vec<Entry>, Option<Range<usize>>)> {
+    let size = usize::try_from(*e.args.first()?).ok()?;
+    let at = usize::try_from(*e.args.get(1)?).ok()?;
+    let private = self::dict(r.0.get(at..at + size)?)?;
+    let subrs = match private.iter().find(|e| e.op == SUBRS) {
+        Some(e) => {
+            let start = at + usize::try_from(*e.args.first()?).ok()?;
+            let (_, end) = index(r, start)?;
+            Some(start..end)
+        }
+        None => None,
+    };
+    Some((private, subrs))
+}
+
+/// INDEX at offset at, result is the item ranges and the end of the INDEX.
+fn index(r: &Reader, at: usize) -> Option<(Vec<Range<usize>>, usize)> {
+    let count = r.u16(at)? as usize;
+    if count == 0 {
+        return Some((Vec::new(), at + 2));
+    }
+    let size = r.u8(at + 2)? as usize;
+    if !(1..=4).contains(&size) {
+        return None;
+    }
+    let offset = |i: usize| {
+        let p = at + 3 + i * size;
+        let b = r.0.get(p..p + size)?;
+        Some(b.iter().fold(0, |x, b| x << 8 | *b as usize))
+    };
+    // Offsets are from the byte before the data.
+    let base = at + 2 + (count + 1) * size;
+    let mut items = Vec::new();
+    for i in 0..count {
+        let (a, b) = (offset(i)?, offset(i + 1)?);
+        if a == 0 || a > b {
+            return None;
+        }
+        items.push(base + a..base + b);
+    }
+    let end = base + offset(count)?;
+    r.0.get(..end)?;
+    Some((items, end))
+}
+
+/// INDEX with the given items.
+fn write_index<T: AsRef<[u8]>>(items: &[T]) -> Vec<u8> {
+    let mut out = (items.len() as u16).to_be_bytes().to_vec();
+    if items.is_empty() {
+        return out;
+    }
+    let last = 1 + items.iter().map(|x| x.as_ref().len()).sum::<usize>();
+    let size = (1..4).find(|s| last < 1 << (8 * s)).unwrap_or(4);
+    out.push(size as u8);
+    let mut offset = 1;
+    out.extend_from_slice(&(offset as u32).to_be_bytes()[4 - size..]);
+    for x in items {
+        offset += x.as_ref().len();
+        out.extend_from_slice(&(offset as u32).to_be_bytes()[4 - size..]);
+    }
+    for x in items {
+        out.extend_from_slice(x.as_ref());
+    }
+    out
+}
+
+/// Parse DICT data.
+fn dict(d: &[u8]) -> Option<Vec<Entry>> {
+    let mut result = Vec::new();
+    let (mut i, mut start, mut args) = (0, 0, Vec::new());
+    while i < d.len() {
+        let b0 = d[i];
+        match b0 {
+            0..=21 => {
+                let raw = d[start..i].to_vec();
+                let mut op = b0 as u16;
+                if b0 == 12 {
+                    op = 0x0c00 | *d.get(i + 1)? as u16;
+                    i += 1;
+                }
+                i += 1;
+                start = i;
+                let args = std::mem::take(&mut args);
+                result.push(Entry { op, raw, args });
+            }
+            28 => {
+                args.push(i16::from_be_bytes(d.get(i + 1..i + 3)?.try_into().ok()?) as i64);
+                i += 3;
+            }
+            29 => {
+                args.push(i32::from_be_bytes(d.get(i + 1..i + 5)?.try_into().ok()?) as i64);
+                i += 5;
+            }
+            30 => {
+                // Real number, nibbles end with 0xf.
+                i += 1;
+                loop {
+                    let b = *d.get(i)?;
+                    i += 1;
+                    if b >> 4 == 0xf || b & 0xf == 0xf {
+                        break;
+                    }
+                }
+                args.push(0);
+            }
+            32..=246 => {
+                args.push(b0 as i64 - 139);
+                i += 1;
+            }
+            247..=250 => {
+                args.push((b0 as i64 - 247) * 256 + *d.get(i + 1)? as i64 + 108);
+                i += 2;
+            }
+            251..=254 => {
+                args.push(-(b0 as i64 - 251) * 256 - *d.get(i + 1)? as i64 - 108);
+                i += 2;
+            }
+            _ => return None,
+        }
+    }
+    Some(result)
+}
+
+/// Append operator.
+fn op(to: &mut Vec<u8>, op: u16) {
+    if op >= 0x0c00 {
+        to.push(12);
+    }
+    to.push(op as u8);
+}
+
+/// Append operands as 5 byte integers, then the operator.
+fn operator(to: &mut Vec<u8>, args: &[i64], o: u16) {
+    for a in args {
+        to.push(29);
+        to.extend_from_slice(&(*a as i32).to_be_bytes());
+    }
+    op(to, o);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ttf::tests::{embedded, font};
+
+    /// Name-keyed CFF with the given charstrings and a Private DICT with one local subroutine.
+    fn cff(charstrings: &[Vec<u8>]) -> Vec<u8> {
+        let names = write_index(&[b"Test"]);
+        let charstrings = write_index(charstrings);
+        let mut private = Vec::new();
+        operator(&mut private, &[500], 20); // defaultWidthX
+        let size = private.len() + 6;
+        operator(&mut private, &[size as i64], SUBRS);
+        private.extend_from_slice(&write_index(&[[11]])); // return
+        let top = |at: usize| {
+            let mut t = Vec::new();
+            operator(&mut t, &[at as i64], CHARSTRINGS);
+            operator(&mut t, &[size as i64, (at + charstrings.len()) as i64], PRIVATE);
+            write_index(&[t])
+        };
+        let empty = write_index::<&[u8]>(&[]);
+        let at = 4 + names.len() + top(0).len() + 2 * empty.len();
+        let mut d = vec![1, 0, 4, 4];
+        for x in [&names, &top(at), &empty, &empty, &charstrings, &private] {
+            d.extend_from_slice(x);
+        }
+        d
+    }
+
+    #[test]
+    fn cff_subset() {
+        let glyphs: Vec<Vec<u8>> = (0..4).map(|g| vec![139 + g, 14]).collect();
+        let data = font(b"OTTO", 4, vec![(*b"CFF ", cff(&glyphs))]);
+        let mut f = OpenTypeFont::new(data).unwrap();
+        assert_eq!(f.cff.fd_select, None);
+        let mut w = BasicPdfWriter::default();
+        w.nocomp = true;
+        f.init(&mut w);
+        f.encode("C", &mut Vec::new()).unwrap();
+        f.finish(&mut w);
+
+        // The subset is CID-keyed, glyphs not used are empty ( endchar ).
+        let sub = embedded(&w.b, b"/Subtype/CIDFontType0C");
+        let c = Cff::parse(&Reader(&sub), 4).unwrap();
+        assert!(c.fd_select.is_some());
+        let cs: Vec<&[u8]> = c.charstrings.iter().map(|x| &sub[x.clone()]).collect();
+        assert_eq!(cs, [&glyphs[0][..], &[14], &[14], &glyphs[3][..]]);
+        let strings: Vec<&[u8]> = c.strings.iter().map(|x| &sub[x.clone()]).collect();
+        assert_eq!(strings, [&b"Adobe"[..], b"Identity"]);
+        assert!(c.top.iter().any(|e| e.op == ROS && e.args == [391, 392, 0]));
+        assert!(c.top.iter().any(|e| e.op == CID_COUNT && e.args == [4]));
+        let [fd] = &c.fds[..] else {
+            panic!("one Font DICT expected")
+        };
+        assert!(fd.private.iter().any(|e| e.op == 20 && e.args == [500]));
+        assert_eq!(fd.subrs.as_ref().map(|s| &sub[s.clone()]), Some(&[0, 1, 1, 1, 2, 11][..]));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::*;
+
+/// Document outline ( bookmark ) entry.
+#[derive(Clone, Debug, Default)]
+pub struct OutlineItem {
+    /// Title shown in the viewer sidebar.
+    pub title: String,
+    /// Nesting level, 1 is the top level.
+    pub level: usize,
+    /// Obj number of destination page ( zero if not yet known ).
+    pub page: usize,
+    /// Destination position ( from bottom of page ).
+    pub y: Px,
+}
+
+impl BasicPdfWriter {
+    /// Write the outline tree, result is obj number of the outline dictionary ( zero if there are no items ).
+    pub fn outlines(&mut self) -> usize {
+        let items = std::mem::take(&mut self.outline);
+        if items.is_empty() {
+            return 0;
+        }
+        let n = items.len();
+
+        // Parent of each item ( n for root ), found using a stack of open items.
+        let mut parent = vec![n; n];
+        let mut stack: Vec<usize> = Vec::new();
+        for (i, item) in items.iter().enumerate() {
+            while let Some(&top) = stack.last() {
+                if items[top].level < item.level {
+                    break;
+                }
+                stack.pop();
+            }
+            if let Some(&top) = stack.last() {
+                parent[i] = top;
+            }
+            stack.push(i);
+        }
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); n + 1];
+        for i in 0..n {
+            children[parent[i]].push(i);
+        }
+        let (mut prev, mut next) = (vec![None; n], vec![None; n]);
+        for kids in &children {
+            for w in kids.windows(2) {
+                next[w[0]] = Some(w[1]);
+                prev[w[1]] = Some(w[0]);
+            }
+        }
+        // Number of descendants, items are in document order so children follow parents.
+        let mut count = vec![0; n + 1];
+        for i in (0..n).rev() {
+            count[parent[i]] += count[i] + 1;
+        }
+
+        let root = self.obj();
+        let objs: Vec<usize> = (0..n).map(|_| self.obj()).collect();
+        let obj = |i: usize| if i == n { root } else { objs[i] };
+
+        let first_last = |kids: &Vec<usize>, d: Dict| {
+            d.with("First", Object::Ref(obj(kids[0])))
+                .with("Last", Object::Ref(obj(kids[kids.len() - 1])))
+        };
+        let d = first_last(&children[n], Dict::new().with("Type", Object::name("Outlines")))
+            .with("Count", count[n]);
+        self.write_obj(root, &Object::Dict(d));
+
+        for (i, item) in items.iter().enumerate() {
+            let mut d = Dict::new()
+                .with("Title", Object::text(&item.title))
+                .with("Parent", Object::Ref(obj(parent[i])));
+            if let Some(k) = prev[i] {
+                d.set("Prev", Object::Ref(obj(k)));
+            }
+            if let Some(k) = next[i] {
+                d.set("Next", Object::Ref(obj(k)));
+            }
+            if !children[i].is_empty() {
+                d = first_last(&children[i], d).with("Count", count[i]);
+            }
+            if item.page != 0 {
+                d.set("Dest", dest(item.page, item.y));
+            }
+            self.write_obj(objs[i], &Object::Dict(d));
+        }
+        root
+    }
+}
@@ -1,17 +1,40 @@
 use crate::font::Font;
 use crate::*;
-use format_bytes::write_bytes as wb;
 use std::collections::BTreeSet;
 
 /// PDF Page, has buffered text stream, text position, font.
 #[derive(Default)]
 pub struct Page {
+    /// PDF obj number ( zero means allocate when page is written ).
+    pub obj: usize,
+
     /// Page width.
     pub width: Px,
 
     /// Page height.
     pub height: Px,
 
+    /// Media box [x0 y0 x1 y1], default ( None ) is [0 0 width height].
+    pub media_box: Option<[f32; 4]>,
+
+    /// Crop box ( region shown or printed ), default is the media box.
+    pub crop_box: Option<[f32; 4]>,
+
+    /// Bleed box ( region content is clipped to in production ), default is the crop box.
+    pub bleed_box: Option<[f32; 4]>,
+
+    /// Trim box ( dimensions of the finished page after trimming ), default is the crop box.
+    pub trim_box: Option<[f32; 4]>,
+
+    /// Art box ( extent of the meaningful content ), default is the crop box.
+    pub art_box: Option<[f32; 4]>,
+
+    /// Rotation ( clockwise, in degrees ) when shown or printed, must be a multiple of 90.
+    pub rotate: i32,
+
+    /// Extra page dictionary entries ( e.g. added by extensions ).
+    pub dict: Dict,
+
     /// Output buffer.
     pub os: Vec<u8>,
 
@@ -21,6 +44,9 @@ pub struct Page {
     /// Current text buffer.
     pub text: Vec<u8>,
 
+    /// Kerning adjustments for text ( position in text, amount ).
+    pub kerns: Vec<(usize, MPx)>,
+
     /// Current line position ( from left of page ).
     pub x: Px,
 
@@ -47,6 +73,15 @@ pub struct Page {
 
     /// Set of other obj numbers used by page.
     pub xobjs: BTreeSet<usize>,
+
+    /// Obj numbers of annotations ( e.g. links ) on page.
+    pub annots: Vec<usize>,
+
+    /// Structure element ( tagged PDF ) of each marked-content sequence, indexed by MCID.
+    pub mcids: Vec<usize>,
+
+    /// Structure element of the marked-content sequence open in the text stream.
+    pub mc: Option<usize>,
 }
 
 impl Page {
@@ -58,72 +93,125 @@ impl Page {
     /// Start a new line ( relative to previous line ).
     pub fn td(&mut self, x: Px, y: Px) {
         self.flush_text();
-        let _ = wb!(&mut self.ts, b"\n{} {} Td ", x, y);
+        self.ts.push(b'\n');
+        op(&[x.into(), y.into()], "Td", &mut self.ts);
         self.x += x;
         self.y += y;
     }
 
-    /// Append text ( encoded with font ).
-    pub fn text(&mut self, font: &dyn Font, size: Px, s: &str) {
+    /// Append text ( encoded with font ), with kerning between the characters of s.
+    pub fn text(&mut self, font: &dyn Font, size: Px, s: &str) -> Result<()> {
         if size != self.font_size || font.obj() != self.font_obj {
             self.flush_text();
             self.font_obj = font.obj();
             self.font_size = size;
         }
-        font.encode(s, &mut self.text);
+        let mut result = Ok(());
+        let mut last = None;
+        for c in s.chars() {
+            if let Some(a) = last {
+                let k = font.kern(a, c);
+                if k != 0 {
+                    self.kerns.push((self.text.len(), k));
+                }
+            }
+            let r = font.encode(c.encode_utf8(&mut [0; 4]), &mut self.text);
+            if result.is_ok() {
+                result = r;
+            }
+            last = Some(c);
+        }
+        result
     }
 
-    /// Leave some space.
+    /// Leave some space, a font must have been set ( see ensure_font ) as the space is a TJ adjustment.
     pub fn space(&mut self, amount: MPx) {
-        let amount = amount / (self.font_size as MPx);
-        let _ = wb!(&mut self.ts, b"[{}] TJ ", -amount);
+        self.flush_text();
+        if self.last_font_size == 0 {
+            return;
+        }
+        let amount = amount / (self.last_font_size as MPx);
+        op(&[vec![Object::from(-amount)].into()], "TJ", &mut self.ts);
     }
 
-    /// Flush text using tj.
-    pub fn flush_text(&mut self) {
-        if self.text.is_empty() {
-            return;
+    /// Set font and size in the text stream if no font has been set yet ( e.g. before space ).
+    pub fn ensure_font(&mut self, font: &dyn Font, size: Px) {
+        self.flush_text();
+        if self.last_font_size == 0 {
+            self.set_font(font.obj(), size);
         }
-        if self.font_obj != self.last_font_obj || self.font_size != self.last_font_size {
-            self.fonts.insert(self.font_obj);
-            let obj = self.font_obj;
-            let size = self.font_size;
-            let _ = wb!(&mut self.ts, b"/F{} {} Tf", obj, size);
+    }
+
+    /// Set font in text stream ( if it has changed ).
+    fn set_font(&mut self, obj: usize, size: Px) {
+        if obj != self.last_font_obj || size != self.last_font_size {
+            self.fonts.insert(obj);
+            op(&[Object::name(&format!("F{}", obj)), size.into()], "Tf", &mut self.ts);
             self.last_font_obj = obj;
             self.last_font_size = size;
         }
-        let mut hex = false;
-        for b in &self.text {
-            if *b < 32 || *b >= 128 {
-                hex = true;
-                break;
-            }
+    }
+
+    /// Flush text using Tj, or TJ if there are kerning adjustments.
+    pub fn flush_text(&mut self) {
+        if self.text.is_empty() {
+            return;
         }
-        if hex {
-            self.ts.push(b'<');
-            for b in &self.text {
-                let x = *b >> 4;
-                self.ts.push(x + if x < 10 { 48 } else { 55 });
-                let x = *b & 15;
-                self.ts.push(x + if x < 10 { 48 } else { 55 });
-            }
-            self.ts.extend_from_slice(b"> Tj");
+        self.set_font(self.font_obj, self.font_size);
+        let text = std::mem::take(&mut self.text);
+        if self.kerns.is_empty() {
+            op(&[Object::String(text)], "Tj", &mut self.ts);
         } else {
-            self.ts.push(b'(');
-            for b in &self.text {
-                let b = *b;
-                if b == b'(' || b == b')' || b == b'\\' {
-                    self.ts.push(b'\\');
-                }
-                self.ts.push(b);
+            // A positive TJ adjustment moves the next character left.
+            let mut a = Vec::new();
+            let mut start = 0;
+            for (at, k) in std::mem::take(&mut self.kerns) {
+                a.push(Object::String(text[start..at].to_vec()));
+                a.push(Object::from(-k));
+                start = at;
             }
-            self.ts.extend_from_slice(b") Tj");
+            a.push(Object::String(text[start..].to_vec()));
+            op(&[a.into()], "TJ", &mut self.ts);
         }
-        self.text.clear();
+    }
+
+    /// Start a marked-content sequence in the text stream for structure element elem ( of type kind ),
+    /// unless one is already open for elem. Result is the MCID of a new sequence.
+    pub fn mark(&mut self, kind: &str, elem: usize) -> Option<usize> {
+        if self.mc == Some(elem) {
+            return None;
+        }
+        self.unmark();
+        self.flush_text();
+        let mcid = self.mcids.len();
+        self.mcids.push(elem);
+        self.ts.push(b'\n');
+        op(&[Object::name(kind), Dict::new().with("MCID", mcid).into()], "BDC", &mut self.ts);
+        self.mc = Some(elem);
+        Some(mcid)
+    }
+
+    /// End the marked-content sequence open in the text stream ( if any ).
+    pub fn unmark(&mut self) {
+        if self.mc.take().is_some() {
+            self.flush_text();
+            op(&[], "EMC", &mut self.ts);
+        }
+    }
+
+    /// Start a marked-content sequence in the output buffer ( e.g. for an image ), result is MCID.
+    /// The sequence must be ended by appending "EMC" to self.os.
+    pub fn mark_os(&mut self, kind: &str, elem: usize) -> usize {
+        let mcid = self.mcids.len();
+        self.mcids.push(elem);
+        self.os.push(b'\n');
+        op(&[Object::name(kind), Dict::new().with("MCID", mcid).into()], "BDC", &mut self.os);
+        mcid
     }
 
     /// Finish page by appending self.ts to self.os enclosed by "BT" and "ET".
     pub fn finish(&mut self) {
+        self.unmark();
         self.flush_text();
         self.os.extend_from_slice(b"\nBT");
         self.os.extend_from_slice(&self.ts);
@@ -135,12 +223,17 @@ impl Page {
 
     /// Draw a line from (x0,y0) to (x1,y1)
     pub fn line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64) {
-        let _ = wb!(&mut self.os, b"\n{} {} m {} {} l S", x0, y0, x1, y1);
+        self.os.push(b'\n');
+        op(&[x0.into(), y0.into()], "m", &mut self.os);
+        op(&[x1.into(), y1.into()], "l", &mut self.os);
+        op(&[], "S", &mut self.os);
     }
 
     /// Draw a rectangle with corners (x0,y0) to (x1,y1)
     pub fn rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64) {
-        let _ = wb!(&mut self.os, b"\n{} {} {} {} re S", x0, y0, x1, y1);
+        self.os.push(b'\n');
+        op(&[x0.into(), y0.into(), x1.into(), y1.into()], "re", &mut self.os);
+        op(&[], "S", &mut self.os);
     }
 
     /// Set level of text on line.
@@ -148,7 +241,7 @@ impl Page {
         if self.sup != sup {
             self.flush_text();
             self.sup = sup;
-            let _ = wb!(&mut self.ts, b" {} Ts", sup);
+            op(&[sup.into()], "Ts", &mut self.ts);
         }
     }
 }
@@ -0,0 +1,109 @@
+//!# Page setup example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::paper::*;
+//!    let mut w = Writer::default();
+//!    w.set_page(PageSetup::paper(PaperSize::A4, Orientation::Portrait));
+//!    html(&mut w, b"<h1>Report</h1><p>Introduction").unwrap();
+//!    // A landscape page for a wide table.
+//!    w.set_page(PageSetup::paper(PaperSize::A4, Orientation::Landscape));
+//!    w.page_break();
+//!    html(&mut w, b"<p>Wide table").unwrap();
+//!    w.set_page(PageSetup::paper(PaperSize::A4, Orientation::Portrait));
+//!    w.page_break();
+//!    html(&mut w, b"<p>Conclusion").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    // Pages are in order, so the landscape box is between the two portrait boxes.
+//!    let boxes: Vec<&[u8]> = bytes
+//!        .windows(22)
+//!        .filter(|x| x.starts_with(b"/MediaBox["))
+//!        .map(|x| &x[10..])
+//!        .collect();
+//!    assert_eq!(boxes, [b"0 0 595 842]", b"0 0 842 595]", b"0 0 595 842]"]);
+//!
+//!    // The same thing using html @page rules.
+//!    let mut w = Writer::default();
+//!    html(&mut w, b"<style>@page { size: A4; margin: 2cm } @page wide { size: A4 landscape }</style>
+//!<p>Introduction<div style=\"page: wide\">Wide table</div><p>Conclusion").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(17).any(|x| x == b"/Type/Pages/Count"));
+//! ```
+
+use crate::*;
+
+/// Named paper size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaperSize {
+    /// 297 x 420 mm
+    A3,
+    /// 210 x 297 mm
+    A4,
+    /// 148 x 210 mm
+    A5,
+    /// 8.5 x 11 in
+    Letter,
+    /// 8.5 x 14 in
+    Legal,
+}
+
+/// Page orientation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    /// Height is greater than width.
+    Portrait,
+    /// Width is greater than height.
+    Landscape,
+}
+
+impl PaperSize {
+    /// Page width and height in points.
+    pub fn size(self, o: Orientation) -> (Px, Px) {
+        let (w, h) = match self {
+            PaperSize::A3 => (842, 1191),
+            PaperSize::A4 => (595, 842),
+            PaperSize::A5 => (420, 595),
+            PaperSize::Letter => (612, 792),
+            PaperSize::Legal => (612, 1008),
+        };
+        match o {
+            Orientation::Portrait => (w, h),
+            Orientation::Landscape => (h, w),
+        }
+    }
+
+    /// Paper size from name ( not case sensitive ), e.g. "a4" or "Letter".
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name.to_ascii_lowercase().as_str() {
+            "a3" => PaperSize::A3,
+            "a4" => PaperSize::A4,
+            "a5" => PaperSize::A5,
+            "letter" => PaperSize::Letter,
+            "legal" => PaperSize::Legal,
+            _ => return None,
+        })
+    }
+}
+
+/// Page size and margins ( see Writer::set_page ).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PageSetup {
+    /// Page width.
+    pub width: Px,
+    /// Page height.
+    pub height: Px,
+    /// Margins ( top, right, bottom, left ), None keeps the current margins.
+    pub margins: Option<[Px; 4]>,
+}
+
+impl PageSetup {
+    /// Page setup for named paper size and orientation ( margins are not changed ).
+    pub fn paper(size: PaperSize, o: Orientation) -> Self {
+        let (width, height) = size.size(o);
+        Self {
+            width,
+            height,
+            margins: None,
+        }
+    }
+}
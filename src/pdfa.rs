@@ -0,0 +1,163 @@
+//!# PDF/A example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::pdfa::Conformance;
+//!    let mut w = Writer::default();
+//!    w.b.conformance = Some(Conformance::PdfA2b);
+//!    html(&mut w, b"<p>Archived").unwrap();
+//!    // The standard fonts are not embedded, so the output is not PDF/A.
+//!    assert!(w.finish().is_err());
+//!    assert!(w.b.violations.iter().any(|v| v.contains("Helvetica")));
+//!
+//!    // Attachments are reported too.
+//!    let mut w = Writer::default();
+//!    w.b.conformance = Some(Conformance::PdfA2b);
+//!    w.attach(&attach::FileSpec { name: "data.csv", data: b"a,b", mime: "text/csv",
+//!        description: "", relationship: attach::Relationship::Data, mod_date: None });
+//!    html(&mut w, b"<p>Archived").unwrap();
+//!    assert!(w.finish().is_err());
+//!    assert!(w.b.violations.iter().any(|v| v.contains("embedded file data.csv")));
+//! ```
+
+use crate::*;
+
+/// PDF/A conformance level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Conformance {
+    /// PDF/A-2b ( ISO 19005-2, basic conformance ).
+    PdfA2b,
+}
+
+impl Conformance {
+    /// PDF/A part ( pdfaid:part ).
+    pub fn part(self) -> u8 {
+        match self {
+            Conformance::PdfA2b => 2,
+        }
+    }
+
+    /// PDF/A conformance level ( pdfaid:conformance ).
+    pub fn level(self) -> &'static str {
+        match self {
+            Conformance::PdfA2b => "B",
+        }
+    }
+}
+
+impl BasicPdfWriter {
+    /// Record a conformance violation ( does nothing if conformance is not set ).
+    pub fn violation(&mut self, msg: String) {
+        if self.conformance.is_some() {
+            self.violations.push(msg);
+        }
+    }
+
+    /// Record violations for settings that are not allowed with PDF/A, set error if there are any violations.
+    pub fn check_conformance(&mut self) {
+        if self.conformance.is_none() {
+            return;
+        }
+        if self.crypt.is_some() {
+            self.violation("encryption is not allowed".to_string());
+        }
+        let files: Vec<String> = self.files.keys().cloned().collect();
+        for name in files {
+            self.violation(format!(
+                "embedded file {} is not allowed ( PDF/A-2 only allows PDF/A attachments )",
+                name
+            ));
+        }
+        if !self.violations.is_empty() {
+            self.set_error(Error::Conformance(self.violations.clone()));
+        }
+    }
+
+    /// Write sRGB output intent ( with embedded ICC profile ), result is obj number of the output intent dictionary.
+    pub fn output_intent(&mut self) -> usize {
+        let icc = self.obj();
+        self.stream_obj(icc, &Dict::new().with("N", 3), &srgb_profile());
+        self.add_obj(&Object::Dict(
+            Dict::new()
+                .with("Type", Object::name("OutputIntent"))
+                .with("S", Object::name("GTS_PDFA1"))
+                .with("OutputConditionIdentifier", Object::text("sRGB IEC61966-2.1"))
+                .with("Info", Object::text("sRGB IEC61966-2.1"))
+                .with("DestOutputProfile", Object::Ref(icc)),
+        ))
+    }
+}
+
+/// ICC ( version 2 ) profile for the sRGB colour space.
+pub fn srgb_profile() -> Vec<u8> {
+    fn xyz(x: f64, y: f64, z: f64) -> Vec<u8> {
+        let mut d = b"XYZ \0\0\0\0".to_vec();
+        for v in [x, y, z] {
+            d.extend_from_slice(&((v * 65536.0).round() as i32).to_be_bytes());
+        }
+        d
+    }
+    let mut desc = b"desc\0\0\0\0".to_vec();
+    let name = b"sRGB IEC61966-2.1\0";
+    desc.extend_from_slice(&(name.len() as u32).to_be_bytes());
+    desc.extend_from_slice(name);
+    desc.resize(desc.len() + 4 + 4 + 2 + 1 + 67, 0);
+    let mut cprt = b"text\0\0\0\0".to_vec();
+    cprt.extend_from_slice(b"No copyright, use freely\0");
+    // Tone reproduction curve, sRGB transfer function sampled at 1024 points.
+    let mut trc = b"curv\0\0\0\0".to_vec();
+    let n = 1024;
+    trc.extend_from_slice(&(n as u32).to_be_bytes());
+    for i in 0..n {
+        let v = i as f64 / (n - 1) as f64;
+        let l = if v <= 0.04045 {
+            v / 12.92
+        } else {
+            ((v + 0.055) / 1.055).powf(2.4)
+        };
+        trc.extend_from_slice(&((l * 65535.0).round() as u16).to_be_bytes());
+    }
+    let tags: Vec<(&[u8; 4], Vec<u8>)> = vec![
+        (b"desc", desc),
+        (b"cprt", cprt),
+        (b"wtpt", xyz(0.9642, 1.0, 0.8249)),
+        (b"rXYZ", xyz(0.4361, 0.2225, 0.0139)),
+        (b"gXYZ", xyz(0.3851, 0.7169, 0.0971)),
+        (b"bXYZ", xyz(0.1431, 0.0606, 0.7141)),
+        (b"rTRC", trc.clone()),
+        (b"gTRC", trc.clone()),
+        (b"bTRC", trc),
+    ];
+
+    let mut table = Vec::new();
+    let mut data = Vec::new();
+    let start = 128 + 4 + 12 * tags.len();
+    table.extend_from_slice(&(tags.len() as u32).to_be_bytes());
+    for (sig, d) in &tags {
+        table.extend_from_slice(&sig[..]);
+        table.extend_from_slice(&((start + data.len()) as u32).to_be_bytes());
+        table.extend_from_slice(&(d.len() as u32).to_be_bytes());
+        data.extend_from_slice(d);
+        while data.len() % 4 != 0 {
+            data.push(0);
+        }
+    }
+
+    let size = 128 + table.len() + data.len();
+    let mut h = Vec::new();
+    h.extend_from_slice(&(size as u32).to_be_bytes());
+    h.extend_from_slice(b"\0\0\0\0"); // CMM
+    h.extend_from_slice(&[2, 0x10, 0, 0]); // Version 2.1
+    h.extend_from_slice(b"mntrRGB XYZ ");
+    for x in [2000u16, 1, 1, 0, 0, 0] {
+        h.extend_from_slice(&x.to_be_bytes()); // Date and time
+    }
+    h.extend_from_slice(b"acsp");
+    h.resize(64, 0); // Platform, flags, manufacturer, model, attributes
+    h.extend_from_slice(&0u32.to_be_bytes()); // Rendering intent ( perceptual )
+    h.extend_from_slice(&xyz(0.9642, 1.0, 0.8249)[8..]); // Illuminant ( D50 )
+    h.resize(128, 0);
+    h.extend_from_slice(&table);
+    h.extend_from_slice(&data);
+    h
+}
@@ -0,0 +1,266 @@
+//!# Signature example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::sign::*;
+//!    let mut w = Writer::default();
+//!    let mut s = Signature::new(Box::new(TestSigner));
+//!    s.name = "A. Signer".to_string();
+//!    s.reason = "Contract approval".to_string();
+//!    w.b.signature = Some(s);
+//!    html(&mut w, b"<p>Terms and conditions...<p>").unwrap();
+//!    w.signature_field("Signature1", 200, 40);
+//!    let bytes = w.finish().unwrap().to_vec();
+//!
+//!    // Check the byte ranges and the ( test ) signature.
+//!    let (range, contents) = signed_ranges(&bytes).unwrap();
+//!    assert_eq!(range[0], 0);
+//!    assert_eq!(range[2] + range[3], bytes.len());
+//!    let mut data = bytes[range[0]..range[1]].to_vec();
+//!    data.extend_from_slice(&bytes[range[2]..range[2] + range[3]]);
+//!    assert_eq!(&contents[..32], &crypt::sha256(&data)[..]);
+//!
+//!    // The document cannot be signed without a signature field.
+//!    let mut w = Writer::default();
+//!    w.b.signature = Some(Signature::new(Box::new(TestSigner)));
+//!    html(&mut w, b"<p>Unsigned").unwrap();
+//!    assert!(matches!(w.finish(), Err(error::Error::Signature(_))));
+//!
+//!    // The signature must be set before the signature field is written ( here by the page break ).
+//!    let mut w = Writer::default();
+//!    w.signature_field("Signature1", 200, 40);
+//!    w.page_break();
+//!    w.b.signature = Some(Signature::new(Box::new(TestSigner)));
+//!    let e = w.finish().unwrap_err().to_string();
+//!    assert!(e.contains("written before b.signature was set"));
+//!
+//!    // Malformed input is rejected rather than causing a panic.
+//!    assert!(signed_ranges(b"/ByteRange[0 5 2 0]/Contents<00>").is_none());
+//!    assert!(signed_ranges(b"<0>/ByteRange[0 0 3 0]").is_none());
+//!    assert_eq!(signed_ranges(b"<12>/ByteRange[0 0 4 0]").unwrap().1, [0x12]);
+//! ```
+
+use crate::*;
+use format_bytes::write_bytes as wb;
+
+/// Creates the signature value, implemented using a crypto library of choice.
+pub trait Signer {
+    /// Maximum size in bytes of the signature, this space is reserved in the signature /Contents.
+    fn max_len(&self) -> usize {
+        8192
+    }
+
+    /// Sign data, which is the PDF file excluding the signature /Contents value, given as two byte ranges.
+    /// Result is a DER encoded CMS ( PKCS#7 ) detached signature, or an error message.
+    fn sign(&mut self, data: &[&[u8]]) -> std::result::Result<Vec<u8>, String>;
+}
+
+/// Signer for testing, the "signature" is the SHA-256 hash of the signed data.
+pub struct TestSigner;
+
+impl Signer for TestSigner {
+    fn max_len(&self) -> usize {
+        32
+    }
+
+    fn sign(&mut self, data: &[&[u8]]) -> std::result::Result<Vec<u8>, String> {
+        Ok(sha256(&data.concat()).to_vec())
+    }
+}
+
+/// Signature settings, the document is signed by finish.
+pub struct Signature {
+    /// Creates the signature value.
+    pub signer: Box<dyn Signer>,
+    /// Name of the person or authority signing.
+    pub name: String,
+    /// Reason for signing.
+    pub reason: String,
+    /// Location of signing.
+    pub location: String,
+    /// Contact information of the signer.
+    pub contact_info: String,
+    /// Time of signing, default ( None ) is the time the PDF is finished.
+    pub date: Option<PdfDate>,
+}
+
+impl Signature {
+    /// New signature settings using signer.
+    pub fn new(signer: Box<dyn Signer>) -> Self {
+        Self {
+            signer,
+            name: String::new(),
+            reason: String::new(),
+            location: String::new(),
+            contact_info: String::new(),
+            date: None,
+        }
+    }
+}
+
+/// Length of each number in the /ByteRange placeholder.
+const RANGE_DIGITS: usize = 10;
+
+impl BasicPdfWriter {
+    /// Obj number of the signature dictionary ( allocated when first needed ).
+    pub fn sig_obj(&mut self) -> usize {
+        if self.sig_obj == 0 {
+            self.sig_obj = self.obj();
+        }
+        self.sig_obj
+    }
+
+    /// Appearance text for a visible signature field.
+    pub fn sig_text(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if let Some(s) = &self.signature {
+            if !s.name.is_empty() {
+                lines.push(format!("Digitally signed by {}", s.name));
+            }
+            if !s.reason.is_empty() {
+                lines.push(format!("Reason: {}", s.reason));
+            }
+            if !s.location.is_empty() {
+                lines.push(format!("Location: {}", s.location));
+            }
+        }
+        lines
+    }
+
+    /// Write the signature dictionary with placeholders for /ByteRange and /Contents ( filled by sign_file ).
+    pub fn write_signature(&mut self) {
+        let Some(s) = &self.signature else {
+            return;
+        };
+        if self.sig_obj == 0 {
+            return;
+        }
+        let date = s.date.unwrap_or_else(|| self.now()).pdf();
+        let mut dict = Dict::new()
+            .with("Type", Object::name("Sig"))
+            .with("Filter", Object::name("Adobe.PPKLite"))
+            .with("SubFilter", Object::name("adbe.pkcs7.detached"))
+            .with("M", Object::text(&date));
+        for (k, v) in [
+            ("Name", &s.name),
+            ("Reason", &s.reason),
+            ("Location", &s.location),
+            ("ContactInfo", &s.contact_info),
+        ] {
+            if !v.is_empty() {
+                dict.set(k, Object::text(v));
+            }
+        }
+        let max_len = s.signer.max_len();
+
+        // The object must not be packed, as the placeholders are located in the file.
+        self.start(self.sig_obj);
+        self.unpacked = true;
+        self.b.extend_from_slice(b"<<");
+        self.entries(&dict);
+        // The /Contents string is not encrypted, so is written directly.
+        let at = self.b.len();
+        let zero = vec![b'0'; RANGE_DIGITS];
+        let _ = wb!(
+            &mut self.b,
+            b"/ByteRange[0 {} {} {}]/Contents",
+            zero,
+            zero,
+            zero
+        );
+        let c0 = self.b.len() - at;
+        self.b.push(b'<');
+        self.b.resize(self.b.len() + max_len * 2, b'0');
+        self.b.push(b'>');
+        let c1 = self.b.len() - at;
+        self.b.extend_from_slice(b">>");
+        let at = self.pos() - (self.b.len() - at);
+        self.sig_at = Some((at + b"/ByteRange[0 ".len(), at + c0, at + c1));
+        self.end();
+    }
+
+    /// Fill in the /ByteRange and /Contents of the signature dictionary, self.b must hold the whole file.
+    pub fn sign_file(&mut self) {
+        let Some(s) = &mut self.signature else {
+            return;
+        };
+        if self.sig_obj == 0 {
+            let msg = if self.sig_field_unlinked {
+                "the signature field was written before b.signature was set"
+            } else {
+                "there is no signature field ( see Writer::signature_field )"
+            };
+            self.set_error(Error::Signature(msg.to_string()));
+            return;
+        }
+        if self.out.is_some() {
+            self.set_error(Error::Signature(
+                "the whole PDF must be buffered ( b.out must be None )".to_string(),
+            ));
+            return;
+        }
+        let Some((p, c0, c1)) = self.sig_at else {
+            return;
+        };
+        // Contents hex string ( including the angle brackets ) is from c0 to c1.
+        let b = &mut self.b;
+        let range = [0, c0, c1, b.len() - c1];
+        let mut r = Vec::new();
+        for x in &range[1..] {
+            let x = x.to_string();
+            r.extend_from_slice(x.as_bytes());
+            r.resize(r.len() + RANGE_DIGITS - x.len(), b' ');
+            r.push(b' ');
+        }
+        r.pop();
+        b[p..p + r.len()].copy_from_slice(&r);
+
+        let sig = match s.signer.sign(&[&b[..c0], &b[c1..]]) {
+            Ok(sig) if sig.len() * 2 > c1 - c0 - 2 => {
+                Err("signature is larger than Signer::max_len".to_string())
+            }
+            sig => sig,
+        };
+        let sig = match sig {
+            Ok(sig) => sig,
+            Err(msg) => {
+                self.set_error(Error::Signature(msg));
+                return;
+            }
+        };
+        let b = &mut self.b;
+        let mut h = Vec::new();
+        hex(&sig, &mut h);
+        b[c0 + 1..c0 + 1 + h.len()].copy_from_slice(&h);
+    }
+}
+
+/// Position of last occurrence of pat in s.
+fn find_last(s: &[u8], pat: &[u8]) -> Option<usize> {
+    (0..=s.len().saturating_sub(pat.len()))
+        .rev()
+        .find(|&i| s[i..].starts_with(pat))
+}
+
+/// Find the signature /ByteRange and decoded /Contents in a signed PDF ( for checking a signature ).
+pub fn signed_ranges(pdf: &[u8]) -> Option<([usize; 4], Vec<u8>)> {
+    let at = find_last(pdf, b"/ByteRange[")? + 11;
+    let end = at + pdf[at..].iter().position(|c| *c == b']')?;
+    let nums: Vec<usize> = std::str::from_utf8(&pdf[at..end])
+        .ok()?
+        .split_whitespace()
+        .filter_map(|n| n.parse().ok())
+        .collect();
+    let range: [usize; 4] = nums.try_into().ok()?;
+    let hex = pdf.get(range[1] + 1..range[2].checked_sub(1)?)?;
+    let digits: Vec<u8> = hex
+        .iter()
+        .filter(|c| !c.is_ascii_whitespace())
+        .map(|c| (*c as char).to_digit(16).map(|d| d as u8))
+        .collect::<Option<_>>()?;
+    if !digits.len().is_multiple_of(2) {
+        return None;
+    }
+    let contents = digits.chunks(2).map(|d| d[0] * 16 + d[1]).collect();
+    Some((range, contents))
+}
@@ -0,0 +1,193 @@
+//!# Tagged PDF example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    let mut w = Writer::default();
+//!    w.b.tagged = true; // Must be set before any output.
+//!    html(&mut w, b"<html lang=en><body><h1>Report</h1><p>Some <a href=\"https://example.com\">linked</a> text.").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(15).any(|x| x == b"/StructTreeRoot"));
+//!    assert!(bytes.windows(9).any(|x| x == b"/Lang(en)"));
+//! ```
+
+use crate::*;
+
+/// Structure element ( node of the structure tree ).
+#[derive(Clone, Debug)]
+pub struct StructElem {
+    /// PDF obj number.
+    pub obj: usize,
+    /// Structure type, e.g. "P", "H1", "Figure".
+    pub kind: &'static str,
+    /// Index of parent element in BasicPdfWriter::structure ( root element is its own parent ).
+    pub parent: usize,
+    /// Alternate description ( e.g. from img alt ).
+    pub alt: Option<String>,
+    /// Content and child elements in reading order.
+    pub kids: Vec<StructKid>,
+}
+
+/// Kid of a structure element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StructKid {
+    /// Child element, index into BasicPdfWriter::structure.
+    Elem(usize),
+    /// Marked-content sequence, page obj number and MCID.
+    Mcid(usize, usize),
+    /// Annotation obj number ( e.g. a link ).
+    Annot(usize),
+}
+
+/// Parent tree entry ( maps content back to structure elements ).
+#[derive(Clone, Debug)]
+pub enum ParentEntry {
+    /// Structure element of each MCID on a page ( indexes into BasicPdfWriter::structure ).
+    Page(Vec<usize>),
+    /// Structure element of an annotation.
+    Annot(usize),
+}
+
+/// Structure type for html tag ( None if the tag is not tagged ).
+pub fn struct_kind(tag: &[u8]) -> Option<&'static str> {
+    Some(match tag {
+        b"p" => "P",
+        b"h1" => "H1",
+        b"h2" => "H2",
+        b"h3" => "H3",
+        b"h4" => "H4",
+        b"h5" => "H5",
+        b"h6" => "H6",
+        b"ul" | b"ol" => "L",
+        b"li" => "LI",
+        b"table" => "Table",
+        b"tr" => "TR",
+        b"th" => "TH",
+        b"td" => "TD",
+        b"img" => "Figure",
+        b"a" => "Link",
+        _ => return None,
+    })
+}
+
+impl BasicPdfWriter {
+    /// Add a structure element, result is index into self.structure. The root ( Document ) element is index 0.
+    pub fn struct_elem(&mut self, kind: &'static str, parent: usize, alt: Option<String>) -> usize {
+        self.struct_root();
+        let obj = self.obj();
+        self.structure.push(StructElem {
+            obj,
+            kind,
+            parent,
+            alt,
+            kids: Vec::new(),
+        });
+        self.structure.len() - 1
+    }
+
+    /// Create the root ( Document ) structure element if it does not yet exist.
+    pub fn struct_root(&mut self) {
+        if self.structure.is_empty() {
+            let obj = self.obj();
+            self.structure.push(StructElem {
+                obj,
+                kind: "Document",
+                parent: 0,
+                alt: None,
+                kids: Vec::new(),
+            });
+        }
+    }
+
+    /// Mark following text on page p as content of structure element elem ( does nothing if not tagged ).
+    pub fn mark_text(&mut self, p: &mut Page, elem: usize) {
+        if self.tagged {
+            self.struct_root();
+            if let Some(mcid) = p.mark(self.structure[elem].kind, elem) {
+                self.structure[elem].kids.push(StructKid::Mcid(p.obj, mcid));
+            }
+        }
+    }
+
+    /// Start a marked-content sequence for structure element elem in the page output buffer ( e.g. for an image ).
+    /// The sequence must be ended by appending "EMC" to p.os.
+    pub fn mark_os(&mut self, p: &mut Page, elem: usize) {
+        self.struct_root();
+        let mcid = p.mark_os(self.structure[elem].kind, elem);
+        self.structure[elem].kids.push(StructKid::Mcid(p.obj, mcid));
+    }
+
+    /// Allocate a parent tree key for an annotation belonging to structure element elem.
+    pub fn annot_struct_parent(&mut self, elem: usize, annot: usize) -> usize {
+        self.structure[elem].kids.push(StructKid::Annot(annot));
+        self.parent_tree.push(ParentEntry::Annot(elem));
+        self.parent_tree.len() - 1
+    }
+
+    /// Allocate a parent tree key for a page, mcids has the structure element of each MCID.
+    pub fn page_struct_parents(&mut self, mcids: &[usize]) -> usize {
+        self.parent_tree.push(ParentEntry::Page(mcids.to_vec()));
+        self.parent_tree.len() - 1
+    }
+
+    /// Write the structure tree, result is obj number of the StructTreeRoot ( zero if not tagged ).
+    pub fn struct_tree(&mut self) -> usize {
+        if !self.tagged {
+            return 0;
+        }
+        self.struct_root();
+        let elems = std::mem::take(&mut self.structure);
+        let parent_tree = std::mem::take(&mut self.parent_tree);
+        let root = self.obj();
+
+        let mut nums = Vec::new();
+        for (key, entry) in parent_tree.iter().enumerate() {
+            nums.push(Object::from(key));
+            nums.push(match entry {
+                ParentEntry::Page(mcids) => {
+                    Object::refs(&mcids.iter().map(|e| elems[*e].obj).collect::<Vec<_>>())
+                }
+                ParentEntry::Annot(e) => Object::Ref(elems[*e].obj),
+            });
+        }
+        let pt = self.add_obj(&Object::Dict(Dict::new().with("Nums", nums)));
+
+        let d = Dict::new()
+            .with("Type", Object::name("StructTreeRoot"))
+            .with("K", Object::Ref(elems[0].obj))
+            .with("ParentTree", Object::Ref(pt))
+            .with("ParentTreeNextKey", parent_tree.len());
+        self.write_obj(root, &Object::Dict(d));
+
+        for (i, e) in elems.iter().enumerate() {
+            let parent = if i == 0 { root } else { elems[e.parent].obj };
+            let mut d = Dict::new()
+                .with("Type", Object::name("StructElem"))
+                .with("S", Object::name(e.kind))
+                .with("P", Object::Ref(parent));
+            if let Some(alt) = &e.alt {
+                d.set("Alt", Object::text(alt));
+            }
+            let kids: Vec<Object> = e
+                .kids
+                .iter()
+                .map(|k| match *k {
+                    StructKid::Elem(c) => Object::Ref(elems[c].obj),
+                    StructKid::Mcid(page, mcid) => Object::Dict(
+                        Dict::new()
+                            .with("Type", Object::name("MCR"))
+                            .with("Pg", Object::Ref(page))
+                            .with("MCID", mcid),
+                    ),
+                    StructKid::Annot(a) => Object::Dict(
+                        Dict::new()
+                            .with("Type", Object::name("OBJR"))
+                            .with("Obj", Object::Ref(a)),
+                    ),
+                })
+                .collect();
+            d.set("K", kids);
+            self.write_obj(e.obj, &Object::Dict(d));
+        }
+        root
+    }
+}
@@ -0,0 +1,787 @@
+//!# TrueType font example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::ttf::TrueTypeFont;
+//!    // Any TrueType font will do, the example is skipped if the font is not installed.
+//!    let path = "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf";
+//!    if let Ok(data) = std::fs::read(path) {
+//!        let mut w = Writer::default();
+//!        w.fonts = [
+//!            Box::new(TrueTypeFont::new(data.clone()).unwrap()),
+//!            Box::new(TrueTypeFont::new(data.clone()).unwrap()),
+//!            Box::new(TrueTypeFont::new(data.clone()).unwrap()),
+//!            Box::new(TrueTypeFont::new(data).unwrap()),
+//!        ];
+//!        html(&mut w, "<p>Zażółć gęślą jaźń. Καλημέρα. Здравствуйте.".as_bytes()).unwrap();
+//!        let bytes = w.finish().unwrap();
+//!        assert!(bytes.windows(14).any(|x| x == b"/CIDFontType2/"));
+//!    }
+//! ```
+
+use crate::*;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+
+/// TrueType font, embedded as a subset of the glyphs used ( Type0 font with Identity-H encoding ).
+///
+/// The font is written by Writer::finish, so it must not be removed from Writer::fonts before then.
+pub struct TrueTypeFont {
+    obj: usize,
+    sfnt: Sfnt,
+    /// Offset of each glyph in the glyf table ( num_glyphs + 1 entries ).
+    loca: Vec<usize>,
+}
+
+/// Tables and metrics common to TrueType and OpenType fonts.
+pub(crate) struct Sfnt {
+    pub data: Vec<u8>,
+    /// PostScript name.
+    pub name: String,
+    pub units_per_em: u16,
+    pub num_glyphs: u16,
+    /// Tables ( tag, offset, length ).
+    pub tables: Vec<([u8; 4], usize, usize)>,
+    /// Advance width of each glyph.
+    pub advances: Vec<u16>,
+    /// Glyph for each character.
+    pub cmap: BTreeMap<char, u16>,
+    pub bbox: [i16; 4],
+    pub ascent: i16,
+    pub descent: i16,
+    pub cap_height: i16,
+    pub x_height: i16,
+    pub italic_angle: f64,
+    pub fixed_pitch: bool,
+    /// Glyphs used so far, with the character ( for the ToUnicode CMap ).
+    pub used: RefCell<BTreeMap<u16, char>>,
+}
+
+/// Big-endian reader for font tables, all reads are bounds checked.
+pub(crate) struct Reader<'a>(pub &'a [u8]);
+
+impl Reader<'_> {
+    pub fn u8(&self, at: usize) -> Option<u8> {
+        self.0.get(at).copied()
+    }
+
+    pub fn u16(&self, at: usize) -> Option<u16> {
+        Some(u16::from_be_bytes(self.0.get(at..at + 2)?.try_into().ok()?))
+    }
+
+    pub fn i16(&self, at: usize) -> Option<i16> {
+        self.u16(at).map(|x| x as i16)
+    }
+
+    pub fn u32(&self, at: usize) -> Option<u32> {
+        Some(u32::from_be_bytes(self.0.get(at..at + 4)?.try_into().ok()?))
+    }
+}
+
+impl Sfnt {
+    /// Parse the table directory and the tables common to all outline formats, version is the expected sfnt version(s).
+    pub fn parse(data: Vec<u8>, versions: &[&[u8; 4]]) -> Option<Self> {
+        let r = Reader(&data);
+        if !versions.iter().any(|v| data.get(0..4) == Some(&v[..])) {
+            return None;
+        }
+        let mut tables = Vec::new();
+        for i in 0..r.u16(4)? as usize {
+            let rec = 12 + i * 16;
+            let tag: [u8; 4] = data.get(rec..rec + 4)?.try_into().ok()?;
+            let (offset, len) = (r.u32(rec + 8)? as usize, r.u32(rec + 12)? as usize);
+            data.get(offset..offset + len)?;
+            tables.push((tag, offset, len));
+        }
+        let table = |tag: &[u8; 4]| tables.iter().find(|t| &t.0 == tag).map(|t| t.1);
+
+        let head = table(b"head")?;
+        // The subset font changes fields up to offset 52 of head, 34 of hhea and 4 of maxp.
+        for (tag, len) in [(b"head", 54), (b"hhea", 36), (b"maxp", 6)] {
+            tables.iter().find(|t| &t.0 == tag && t.2 >= len)?;
+        }
+        let units_per_em = r.u16(head + 18)?.max(1);
+        let bbox = [
+            r.i16(head + 36)?,
+            r.i16(head + 38)?,
+            r.i16(head + 40)?,
+            r.i16(head + 42)?,
+        ];
+        let num_glyphs = r.u16(table(b"maxp")? + 4)?;
+        if num_glyphs == 0 {
+            return None;
+        }
+
+        let hhea = table(b"hhea")?;
+        let ascent = r.i16(hhea + 4)?;
+        let descent = r.i16(hhea + 6)?;
+        let num_metrics = r.u16(hhea + 34)?.max(1);
+        let hmtx = table(b"hmtx")?;
+        let mut advances = Vec::new();
+        for g in 0..num_glyphs {
+            let m = g.min(num_metrics - 1) as usize;
+            advances.push(r.u16(hmtx + m * 4)?);
+        }
+
+        // Without version 2 of the OS/2 table, cap height and x-height are estimated from the ascent.
+        let (cap_height, x_height) = match table(b"OS/2") {
+            Some(os2) if r.u16(os2)? >= 2 => (r.i16(os2 + 88)?, r.i16(os2 + 86)?),
+            _ => (ascent, ascent / 2),
+        };
+        let (italic_angle, fixed_pitch) = match table(b"post") {
+            Some(post) => (
+                r.u32(post + 4)? as i32 as f64 / 65536.0,
+                r.u32(post + 12)? != 0,
+            ),
+            None => (0.0, false),
+        };
+        let cmap = cmap(&r, table(b"cmap")?, num_glyphs)?;
+        let name = table(b"name")
+            .and_then(|n| ps_name(&r, n))
+            .unwrap_or_else(|| "Font".to_string());
+
+        Some(Self {
+            name,
+            units_per_em,
+            num_glyphs,
+            tables,
+            advances,
+            cmap,
+            bbox,
+            ascent,
+            descent,
+            cap_height,
+            x_height,
+            italic_angle,
+            fixed_pitch,
+            used: RefCell::new(BTreeMap::new()),
+            data,
+        })
+    }
+
+    /// Table offset and length.
+    pub fn table(&self, tag: &[u8; 4]) -> Option<(usize, usize)> {
+        self.tables
+            .iter()
+            .find(|t| &t.0 == tag)
+            .map(|t| (t.1, t.2))
+    }
+
+    /// Glyph for character c ( zero if the font has no glyph for c ).
+    pub fn glyph(&self, c: char) -> u16 {
+        self.cmap.get(&c).copied().unwrap_or(0)
+    }
+
+    /// Glyph units scaled to 1000 units per em.
+    pub fn scale(&self, x: i64) -> i64 {
+        x * 1000 / self.units_per_em as i64
+    }
+
+    /// Metrics scaled to 1000 units per em.
+    pub fn metrics(&self) -> FontMetrics {
+        let s = |x: i16| self.scale(x as i64) as i32;
+        FontMetrics {
+            ascent: s(self.ascent),
+            descent: s(self.descent),
+            cap_height: s(self.cap_height),
+            x_height: s(self.x_height),
+            bbox: self.bbox.map(s),
+        }
+    }
+
+    /// Width of character c ( 1000 units per em ).
+    pub fn width(&self, c: char) -> MPx {
+        let g = self.glyph(c) as usize;
+        self.scale(self.advances.get(g).copied().unwrap_or(0) as i64)
+    }
+
+    /// Encode s as 2-byte glyph numbers, recording the glyphs used.
+    pub fn encode(&self, s: &str, to: &mut Vec<u8>) -> Result<()> {
+        let mut result = Ok(());
+        let mut used = self.used.borrow_mut();
+        for c in s.chars() {
+            let g = self.glyph(c);
+            if g == 0 && result.is_ok() {
+                result = Err(Error::Encoding(self.name.clone(), c));
+            }
+            used.entry(g).or_insert(c);
+            to.extend_from_slice(&g.to_be_bytes());
+        }
+        result
+    }
+
+    /// Font name with subset tag, the tag is derived from the glyphs kept, so output is reproducible.
+    pub fn subset_name(&self, keep: &BTreeSet<u16>) -> String {
+        let mut ids = Vec::new();
+        for g in keep {
+            ids.extend_from_slice(&g.to_be_bytes());
+        }
+        let tag: String = md5(&ids)[..6]
+            .iter()
+            .map(|x| (b'A' + x % 26) as char)
+            .collect();
+        format!("{}+{}", tag, self.name)
+    }
+
+    /// Write Type0 font obj, with a descendant CIDFont ( glyphs are selected by CID = glyph number ).
+    /// file is the font file key and obj number, cid has the CIDFont Subtype and any extra entries.
+    pub fn write_type0(
+        &self,
+        w: &mut BasicPdfWriter,
+        obj: usize,
+        base: &str,
+        used: &BTreeMap<u16, char>,
+        file: (&str, usize),
+        cid: Dict,
+    ) {
+        let s = |x: i16| Object::Int(self.scale(x as i64));
+        let flags = if self.fixed_pitch { 1 } else { 0 }
+            + 4 // Symbolic ( glyphs outside the standard Latin set )
+            + if self.italic_angle != 0.0 { 64 } else { 0 };
+        let fd = Dict::new()
+            .with("Type", Object::name("FontDescriptor"))
+            .with("FontName", Object::name(base))
+            .with("Flags", flags)
+            .with(
+                "FontBBox",
+                self.bbox.iter().map(|x| s(*x)).collect::<Vec<_>>(),
+            )
+            .with("ItalicAngle", self.italic_angle)
+            .with("Ascent", s(self.ascent))
+            .with("Descent", s(self.descent))
+            .with("CapHeight", s(self.cap_height))
+            .with("XHeight", s(self.x_height))
+            .with("StemV", 80)
+            .with(file.0, Object::Ref(file.1));
+        let fd = w.add_obj(&Object::Dict(fd));
+
+        // Widths, as runs of consecutive glyphs: first [w1 w2 ...].
+        let mut widths = Vec::new();
+        let mut run: Vec<Object> = Vec::new();
+        let mut next = 0;
+        for &g in used.keys() {
+            if g != next && !run.is_empty() {
+                widths.push(Object::Array(std::mem::take(&mut run)));
+            }
+            if run.is_empty() {
+                widths.push(Object::Int(g as i64));
+            }
+            let a = self.advances.get(g as usize).copied().unwrap_or(0);
+            run.push(Object::Int(self.scale(a as i64)));
+            next = g + 1;
+        }
+        if !run.is_empty() {
+            widths.push(Object::Array(run));
+        }
+
+        let mut cid_font = Dict::new().with("Type", Object::name("Font"));
+        cid_font.0.extend(cid.0);
+        let cid_font = cid_font
+            .with("BaseFont", Object::name(base))
+            .with(
+                "CIDSystemInfo",
+                Dict::new()
+                    .with("Registry", Object::String(b"Adobe".to_vec()))
+                    .with("Ordering", Object::String(b"Identity".to_vec()))
+                    .with("Supplement", 0),
+            )
+            .with("FontDescriptor", Object::Ref(fd))
+            .with("DW", self.scale(self.advances[0] as i64))
+            .with("W", widths);
+        let cid_font = w.add_obj(&Object::Dict(cid_font));
+
+        let to_unicode = w.add_obj(&Object::Stream(Dict::new(), to_unicode(used)));
+        let d = Dict::new()
+            .with("Type", Object::name("Font"))
+            .with("Subtype", Object::name("Type0"))
+            .with("BaseFont", Object::name(base))
+            .with("Encoding", Object::name("Identity-H"))
+            .with("DescendantFonts", vec![Object::Ref(cid_font)])
+            .with("ToUnicode", Object::Ref(to_unicode));
+        w.write_obj(obj, &Object::Dict(d));
+    }
+}
+
+impl TrueTypeFont {
+    /// Parse TrueType font data ( a .ttf file ). Error if the data is not a valid TrueType font.
+    pub fn new(data: Vec<u8>) -> Result<Self> {
+        Self::parse(data).ok_or_else(|| Error::Font("not a valid TrueType font".to_string()))
+    }
+
+    fn parse(data: Vec<u8>) -> Option<Self> {
+        let sfnt = Sfnt::parse(data, &[&[0, 1, 0, 0], b"true"])?;
+        let r = Reader(&sfnt.data);
+        let (head, _) = sfnt.table(b"head")?;
+        let long_loca = r.i16(head + 50)? != 0;
+        let (loca_at, _) = sfnt.table(b"loca")?;
+        let (glyf, _) = sfnt.table(b"glyf")?;
+        let mut loca = Vec::new();
+        for g in 0..=sfnt.num_glyphs as usize {
+            loca.push(
+                glyf + if long_loca {
+                    r.u32(loca_at + g * 4)? as usize
+                } else {
+                    r.u16(loca_at + g * 2)? as usize * 2
+                },
+            );
+        }
+        Some(Self { obj: 0, sfnt, loca })
+    }
+
+    /// Font name ( PostScript name from the name table ).
+    pub fn name(&self) -> &str {
+        &self.sfnt.name
+    }
+
+    /// Glyph for character c ( zero if the font has no glyph for c ).
+    pub fn glyph(&self, c: char) -> u16 {
+        self.sfnt.glyph(c)
+    }
+
+    /// Glyph data from the glyf table.
+    fn glyph_data(&self, g: u16) -> &[u8] {
+        let g = g as usize;
+        match (self.loca.get(g), self.loca.get(g + 1)) {
+            (Some(&a), Some(&b)) if a < b => self.sfnt.data.get(a..b).unwrap_or(&[]),
+            _ => &[],
+        }
+    }
+    /// Glyphs used by a composite glyph.
+    fn components(&self, g: u16) -> Vec<u16> {
+        let r = Reader(self.glyph_data(g));
+        let mut result = Vec::new();
+        if r.i16(0).is_none_or(|n| n >= 0) {
+            return result;
+        }
+        let mut at = 10;
+        while let (Some(flags), Some(c)) = (r.u16(at), r.u16(at + 2)) {
+            result.push(c);
+            at += 4 + if flags & 1 != 0 { 4 } else { 2 };
+            at += match flags {
+                f if f & 0x08 != 0 => 2,
+                f if f & 0x40 != 0 => 4,
+                f if f & 0x80 != 0 => 8,
+                _ => 0,
+            };
+            if flags & 0x20 == 0 {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Font file with only the glyphs in keep ( glyph numbers are unchanged ).
+    /// Glyphs after the last glyph kept are removed from the font.
+    fn subset(&self, keep: &BTreeSet<u16>) -> Vec<u8> {
+        let last = keep.last().copied().unwrap_or(0);
+        let num_glyphs = (last + 1).min(self.sfnt.num_glyphs);
+        let r = Reader(&self.sfnt.data);
+        let hhea = self.sfnt.table(b"hhea").map_or(0, |t| t.0);
+        let num_metrics = r.u16(hhea + 34).unwrap_or(1).clamp(1, num_glyphs);
+        // Long metrics ( advance and left side bearing ), then left side bearings for the other glyphs.
+        let hmtx_len = num_metrics as usize * 4 + (num_glyphs - num_metrics) as usize * 2;
+
+        let mut glyf = Vec::new();
+        let mut loca = Vec::new();
+        for g in 0..num_glyphs {
+            loca.extend_from_slice(&(glyf.len() as u32).to_be_bytes());
+            if keep.contains(&g) {
+                glyf.extend_from_slice(self.glyph_data(g));
+                glyf.resize(glyf.len().div_ceil(4) * 4, 0);
+            }
+        }
+        loca.extend_from_slice(&(glyf.len() as u32).to_be_bytes());
+
+        let mut tables: Vec<([u8; 4], Vec<u8>)> = Vec::new();
+        for (tag, offset, len) in &self.sfnt.tables {
+            let t = &self.sfnt.data[*offset..offset + len];
+            match tag {
+                b"head" => {
+                    let mut head = t.to_vec();
+                    head[8..12].fill(0); // checkSumAdjustment, set below
+                    head[50..52].copy_from_slice(&1u16.to_be_bytes()); // Long loca
+                    tables.push((*tag, head));
+                }
+                b"hhea" => {
+                    let mut hhea = t.to_vec();
+                    hhea[34..36].copy_from_slice(&num_metrics.to_be_bytes());
+                    tables.push((*tag, hhea));
+                }
+                b"maxp" => {
+                    let mut maxp = t.to_vec();
+                    maxp[4..6].copy_from_slice(&num_glyphs.to_be_bytes());
+                    tables.push((*tag, maxp));
+                }
+                b"hmtx" => tables.push((*tag, t[..hmtx_len.min(t.len())].to_vec())),
+                b"cvt " | b"fpgm" | b"prep" => tables.push((*tag, t.to_vec())),
+                _ => {}
+            }
+        }
+        tables.push((*b"loca", loca));
+        tables.push((*b"glyf", glyf));
+        tables.sort_by_key(|t| t.0);
+
+        let n = tables.len();
+        let selector = n.ilog2() as usize;
+        let mut font = Vec::new();
+        font.extend_from_slice(&0x00010000u32.to_be_bytes());
+        for x in [n, 16 << selector, selector, n * 16 - (16 << selector)] {
+            font.extend_from_slice(&(x as u16).to_be_bytes());
+        }
+        let mut offset = 12 + n * 16;
+        for (tag, t) in &tables {
+            font.extend_from_slice(tag);
+            font.extend_from_slice(&checksum(t).to_be_bytes());
+            font.extend_from_slice(&(offset as u32).to_be_bytes());
+            font.extend_from_slice(&(t.len() as u32).to_be_bytes());
+            offset += t.len().div_ceil(4) * 4;
+        }
+        let mut head = 0;
+        for (tag, t) in &tables {
+            if tag == b"head" {
+                head = font.len();
+            }
+            font.extend_from_slice(t);
+            font.resize(font.len().div_ceil(4) * 4, 0);
+        }
+        let adjust = 0xB1B0AFBAu32.wrapping_sub(checksum(&font));
+        font[head + 8..head + 12].copy_from_slice(&adjust.to_be_bytes());
+        font
+    }
+}
+
+impl Font for TrueTypeFont {
+    fn obj(&self) -> usize {
+        self.obj
+    }
+
+    fn width(&self, c: char) -> MPx {
+        self.sfnt.width(c)
+    }
+
+    fn metrics(&self) -> FontMetrics {
+        self.sfnt.metrics()
+    }
+
+    fn init(&mut self, w: &mut BasicPdfWriter) {
+        if self.obj == 0 {
+            self.obj = w.obj();
+        }
+    }
+
+    fn encode(&self, s: &str, to: &mut Vec<u8>) -> Result<()> {
+        self.sfnt.encode(s, to)
+    }
+
+    fn finish(&mut self, w: &mut BasicPdfWriter) {
+        if self.obj == 0 {
+            return;
+        }
+        let mut used = self.sfnt.used.take();
+        used.remove(&0);
+        let mut keep: BTreeSet<u16> = used.keys().copied().collect();
+        keep.insert(0);
+        let mut todo: Vec<u16> = keep.iter().copied().collect();
+        while let Some(g) = todo.pop() {
+            for c in self.components(g) {
+                if keep.insert(c) {
+                    todo.push(c);
+                }
+            }
+        }
+        let base = self.sfnt.subset_name(&keep);
+        let font = self.subset(&keep);
+        let file = w.add_obj(&Object::Stream(
+            Dict::new().with("Length1", font.len()),
+            font,
+        ));
+        let cid = Dict::new()
+            .with("Subtype", Object::name("CIDFontType2"))
+            .with("CIDToGIDMap", Object::name("Identity"));
+        self.sfnt
+            .write_type0(w, self.obj, &base, &used, ("FontFile2", file), cid);
+    }
+}
+
+/// ToUnicode CMap for the glyphs used.
+fn to_unicode(used: &BTreeMap<u16, char>) -> Vec<u8> {
+    let mut s = Vec::new();
+    s.extend_from_slice(b"/CIDInit/ProcSet findresource begin\n12 dict begin\nbegincmap\n");
+    s.extend_from_slice(b"/CIDSystemInfo<</Registry(Adobe)/Ordering(UCS)/Supplement 0>>def\n");
+    s.extend_from_slice(b"/CMapName/Adobe-Identity-UCS def\n/CMapType 2 def\n");
+    s.extend_from_slice(b"1 begincodespacerange\n<0000><FFFF>\nendcodespacerange\n");
+    let used: Vec<_> = used.iter().collect();
+    for chunk in used.chunks(100) {
+        s.extend_from_slice(format!("{} beginbfchar\n", chunk.len()).as_bytes());
+        for (g, c) in chunk {
+            s.push(b'<');
+            hex(&g.to_be_bytes(), &mut s);
+            s.push(b'>');
+            let mut u = Vec::new();
+            for x in c.encode_utf16(&mut [0; 2]) {
+                u.extend_from_slice(&x.to_be_bytes());
+            }
+            s.push(b'<');
+            hex(&u, &mut s);
+            s.push(b'>');
+            s.push(b'\n');
+        }
+        s.extend_from_slice(b"endbfchar\n");
+    }
+    s.extend_from_slice(b"endcmap\nCMapName currentdict/CMap defineresource pop\nend\nend\n");
+    s
+}
+
+/// TrueType table checksum.
+fn checksum(t: &[u8]) -> u32 {
+    t.chunks(4).fold(0u32, |sum, c| {
+        let mut x = [0; 4];
+        x[..c.len()].copy_from_slice(c);
+        sum.wrapping_add(u32::from_be_bytes(x))
+    })
+}
+
+/// Character to glyph map from the cmap table ( Unicode subtable, format 4 or 12 ), glyphs not in the font are skipped.
+fn cmap(r: &Reader, cmap: usize, num_glyphs: u16) -> Option<BTreeMap<char, u16>> {
+    let mut best = None;
+    for i in 0..r.u16(cmap + 2)? as usize {
+        let rec = cmap + 4 + i * 8;
+        let (platform, encoding) = (r.u16(rec)?, r.u16(rec + 2)?);
+        let at = cmap + r.u32(rec + 4)? as usize;
+        let format = r.u16(at)?;
+        let rank = match (platform, encoding, format) {
+            (3, 10, 12) | (0, _, 12) => 2,
+            (3, 1, 4) | (0, _, 4) => 1,
+            _ => continue,
+        };
+        if best.is_none_or(|(r, _)| rank > r) {
+            best = Some((rank, at));
+        }
+    }
+    let (_, at) = best?;
+    let mut map = BTreeMap::new();
+    if r.u16(at)? == 12 {
+        for i in 0..r.u32(at + 12)? as usize {
+            let g = at + 16 + i * 12;
+            let (start, end, glyph) = (r.u32(g)?, r.u32(g + 4)?, r.u32(g + 8)?);
+            for c in start..=end.min(0x10ffff) {
+                // Glyphs increase with c, so the rest of the group is past the last glyph too.
+                match glyph.checked_add(c - start) {
+                    Some(g) if g < num_glyphs as u32 => {
+                        if let Some(ch) = char::from_u32(c) {
+                            map.insert(ch, g as u16);
+                        }
+                    }
+                    _ => break,
+                }
+            }
+        }
+    } else {
+        let segs = r.u16(at + 6)? as usize / 2;
+        let ends = at + 14;
+        let starts = ends + segs * 2 + 2;
+        let deltas = starts + segs * 2;
+        let offsets = deltas + segs * 2;
+        for s in 0..segs {
+            let (start, end) = (r.u16(starts + s * 2)?, r.u16(ends + s * 2)?);
+            let delta = r.u16(deltas + s * 2)?;
+            let ro = r.u16(offsets + s * 2)? as usize;
+            for c in start..=end {
+                let g = if ro == 0 {
+                    c.wrapping_add(delta)
+                } else {
+                    let p = offsets + s * 2 + ro + (c - start) as usize * 2;
+                    match r.u16(p)? {
+                        0 => 0,
+                        g => g.wrapping_add(delta),
+                    }
+                };
+                if g != 0
+                    && g < num_glyphs
+                    && let Some(ch) = char::from_u32(c as u32)
+                {
+                    map.insert(ch, g);
+                }
+                if c == 0xffff {
+                    break;
+                }
+            }
+        }
+    }
+    Some(map)
+}
+
+/// PostScript name ( name id 6 ) from the name table.
+fn ps_name(r: &Reader, name: usize) -> Option<String> {
+    let strings = name + r.u16(name + 4)? as usize;
+    for i in 0..r.u16(name + 2)? as usize {
+        let rec = name + 6 + i * 12;
+        if r.u16(rec + 6)? != 6 {
+            continue;
+        }
+        let (platform, len, offset) = (r.u16(rec)?, r.u16(rec + 8)?, r.u16(rec + 10)?);
+        let at = strings + offset as usize;
+        let bytes = r.0.get(at..at + len as usize)?;
+        let s: String = match platform {
+            3 | 0 => char::decode_utf16(
+                bytes
+                    .chunks(2)
+                    .map(|c| u16::from_be_bytes([c[0], *c.get(1).unwrap_or(&0)])),
+            )
+            .filter_map(|c| c.ok())
+            .collect(),
+            _ => bytes.iter().map(|b| *b as char).collect(),
+        };
+        let s: String = s.chars().filter(|c| c.is_ascii_graphic()).collect();
+        if !s.is_empty() {
+            return Some(s);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Font file with the given sfnt version, glyph count and outline tables.
+    /// Glyph g has advance width 500 + 10 * g, characters from 'A' map to glyphs 1 to num_glyphs - 1.
+    pub(crate) fn font(version: &[u8; 4], num_glyphs: u16, outlines: Vec<([u8; 4], Vec<u8>)>) -> Vec<u8> {
+        let mut head = vec![0; 54];
+        head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+        head[50..52].copy_from_slice(&1u16.to_be_bytes());
+        let mut hhea = vec![0; 36];
+        hhea[4..6].copy_from_slice(&800i16.to_be_bytes());
+        hhea[6..8].copy_from_slice(&(-200i16).to_be_bytes());
+        hhea[34..36].copy_from_slice(&num_glyphs.to_be_bytes());
+        let mut maxp = vec![0, 0, 0x50, 0];
+        maxp.extend_from_slice(&num_glyphs.to_be_bytes());
+        let mut hmtx = Vec::new();
+        for g in 0..num_glyphs {
+            hmtx.extend_from_slice(&(500 + 10 * g).to_be_bytes());
+            hmtx.extend_from_slice(&[0, 0]);
+        }
+        let last = 0x41 + num_glyphs as u32 - 2;
+        let mut tables = vec![
+            (*b"head", head),
+            (*b"hhea", hhea),
+            (*b"maxp", maxp),
+            (*b"hmtx", hmtx),
+            (*b"cmap", cmap12(&[(0x41, last, 1)])),
+        ];
+        tables.extend(outlines);
+        tables.sort_by_key(|t| t.0);
+
+        let mut f = version.to_vec();
+        f.extend_from_slice(&(tables.len() as u16).to_be_bytes());
+        f.extend_from_slice(&[0; 6]);
+        let mut offset = 12 + tables.len() * 16;
+        for (tag, t) in &tables {
+            f.extend_from_slice(tag);
+            f.extend_from_slice(&checksum(t).to_be_bytes());
+            f.extend_from_slice(&(offset as u32).to_be_bytes());
+            f.extend_from_slice(&(t.len() as u32).to_be_bytes());
+            offset += t.len().div_ceil(4) * 4;
+        }
+        for (_, t) in &tables {
+            f.extend_from_slice(t);
+            f.resize(f.len().div_ceil(4) * 4, 0);
+        }
+        f
+    }
+
+    /// Contents of the stream with a dictionary containing key, in a PDF written with nocomp set.
+    pub(crate) fn embedded(pdf: &[u8], key: &[u8]) -> Vec<u8> {
+        let find = |s: &[u8], from: usize| {
+            from + pdf[from..].windows(s.len()).position(|x| x == s).unwrap()
+        };
+        let at = find(b"/Length ", find(key, 0)) + 8;
+        let digits = pdf[at..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let len: usize = std::str::from_utf8(&pdf[at..at + digits]).unwrap().parse().unwrap();
+        let start = find(b"stream\n", at) + 7;
+        pdf[start..start + len].to_vec()
+    }
+
+    /// Tables of a font file ( tag, data ).
+    fn tables(f: &[u8]) -> BTreeMap<[u8; 4], &[u8]> {
+        let r = Reader(f);
+        (0..r.u16(4).unwrap() as usize)
+            .map(|i| {
+                let rec = 12 + i * 16;
+                let (at, len) = (r.u32(rec + 8).unwrap() as usize, r.u32(rec + 12).unwrap() as usize);
+                (f[rec..rec + 4].try_into().unwrap(), &f[at..at + len])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn truetype_subset() {
+        // Simple glyphs ( no contours ) told apart by xMax, glyph 2 is a composite of glyph 3.
+        let simple = |g: u8| vec![0, 0, 0, 0, 0, 0, 0, g, 0, 0, 0, 0];
+        let composite = vec![0xff, 0xff, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0];
+        let glyphs = [simple(0), simple(1), composite.clone(), simple(3), simple(4), simple(5)];
+        let (mut glyf, mut loca) = (Vec::new(), Vec::new());
+        for g in &glyphs {
+            loca.extend_from_slice(&(glyf.len() as u32).to_be_bytes());
+            glyf.extend_from_slice(g);
+        }
+        loca.extend_from_slice(&(glyf.len() as u32).to_be_bytes());
+        let data = font(&[0, 1, 0, 0], 6, vec![(*b"loca", loca), (*b"glyf", glyf)]);
+
+        let mut f = TrueTypeFont::new(data).unwrap();
+        assert_eq!((f.glyph('A'), f.glyph('E'), f.glyph('F')), (1, 5, 0));
+        assert_eq!(f.width('B'), 520);
+        let mut w = BasicPdfWriter::default();
+        w.nocomp = true;
+        f.init(&mut w);
+        let mut text = Vec::new();
+        f.encode("B", &mut text).unwrap();
+        assert_eq!(text, [0, 2]);
+        assert!(matches!(f.encode("?", &mut text), Err(Error::Encoding(_, '?'))));
+        f.finish(&mut w);
+
+        // Glyph numbers are unchanged, glyph 1 is empty and glyphs after the component are removed.
+        let sub = embedded(&w.b, b"/Length1 ");
+        assert_eq!(checksum(&sub), 0xB1B0AFBA);
+        let t = tables(&sub);
+        assert_eq!(Reader(t[b"maxp"]).u16(4), Some(4));
+        let (loca, glyf) = (Reader(t[b"loca"]), t[b"glyf"]);
+        let glyph = |g: usize| {
+            let (a, b) = (loca.u32(g * 4).unwrap(), loca.u32(g * 4 + 4).unwrap());
+            &glyf[a as usize..b as usize]
+        };
+        assert_eq!(glyph(0), &glyphs[0][..]);
+        assert!(glyph(1).is_empty());
+        assert_eq!(glyph(2), &composite[..]);
+        assert_eq!(glyph(3), &glyphs[3][..]);
+        assert!(loca.u32(20).is_none());
+        assert!(w.b.windows(17).any(|x| x == b"/DW 500/W[2[520]]"));
+    }
+
+    /// cmap table with a single format 12 subtable holding groups ( start, end, glyph ).
+    fn cmap12(groups: &[(u32, u32, u32)]) -> Vec<u8> {
+        let mut t = vec![0, 0, 0, 1, 0, 3, 0, 10, 0, 0, 0, 12];
+        t.extend_from_slice(&[0, 12, 0, 0]);
+        t.extend_from_slice(&(16 + groups.len() as u32 * 12).to_be_bytes());
+        t.extend_from_slice(&[0; 4]);
+        t.extend_from_slice(&(groups.len() as u32).to_be_bytes());
+        for (start, end, glyph) in groups {
+            for x in [start, end, glyph] {
+                t.extend_from_slice(&x.to_be_bytes());
+            }
+        }
+        t
+    }
+
+    #[test]
+    fn cmap_format_12_bounds() {
+        let t = cmap12(&[
+            (0x41, 0x44, 1),
+            (0x61, 0x10ffff, 3),
+            (0x30, 0x31, u32::MAX),
+        ]);
+        let map = cmap(&Reader(&t), 0, 5).unwrap();
+        let expect = [('A', 1), ('B', 2), ('C', 3), ('D', 4), ('a', 3), ('b', 4)];
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), expect);
+    }
+}
@@ -0,0 +1,174 @@
+//!# Viewer example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    use pdf_min::viewer::*;
+//!    let mut w = Writer::default();
+//!    w.b.viewer.page_layout = Some(PageLayout::TwoPageRight);
+//!    w.b.viewer.open_action = Some((0, Zoom::FitWidth));
+//!    w.b.viewer.prefs.display_doc_title = true;
+//!    html(&mut w, b"<title>Brochure</title><body><h1>Brochure</h1><p>Hello").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(17).any(|x| x == b"/DisplayDocTitle "));
+//!    // There is only a /PageMode if one is set ( even though the heading is a bookmark ).
+//!    assert!(bytes.windows(9).any(|x| x == b"/Outlines"));
+//!    assert!(!bytes.windows(9).any(|x| x == b"/PageMode"));
+//! ```
+
+use crate::*;
+
+/// How the document is displayed when opened ( catalog /PageMode ).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageMode {
+    /// Neither outline nor thumbnails visible.
+    UseNone,
+    /// Outline ( bookmarks ) visible.
+    UseOutlines,
+    /// Thumbnail images visible.
+    UseThumbs,
+    /// Full screen, no menu bar or window controls.
+    FullScreen,
+    /// Optional content group panel visible.
+    UseOC,
+    /// Attachments panel visible.
+    UseAttachments,
+}
+
+impl PageMode {
+    fn name(self) -> &'static str {
+        match self {
+            PageMode::UseNone => "UseNone",
+            PageMode::UseOutlines => "UseOutlines",
+            PageMode::UseThumbs => "UseThumbs",
+            PageMode::FullScreen => "FullScreen",
+            PageMode::UseOC => "UseOC",
+            PageMode::UseAttachments => "UseAttachments",
+        }
+    }
+}
+
+/// Page layout used when the document is opened ( catalog /PageLayout ).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageLayout {
+    /// One page at a time.
+    SinglePage,
+    /// Pages in one column.
+    OneColumn,
+    /// Pages in two columns, odd numbered pages on the left.
+    TwoColumnLeft,
+    /// Pages in two columns, odd numbered pages on the right.
+    TwoColumnRight,
+    /// Two pages at a time, odd numbered pages on the left.
+    TwoPageLeft,
+    /// Two pages at a time, odd numbered pages on the right ( facing spreads ).
+    TwoPageRight,
+}
+
+impl PageLayout {
+    fn name(self) -> &'static str {
+        match self {
+            PageLayout::SinglePage => "SinglePage",
+            PageLayout::OneColumn => "OneColumn",
+            PageLayout::TwoColumnLeft => "TwoColumnLeft",
+            PageLayout::TwoColumnRight => "TwoColumnRight",
+            PageLayout::TwoPageLeft => "TwoPageLeft",
+            PageLayout::TwoPageRight => "TwoPageRight",
+        }
+    }
+}
+
+/// Zoom used by the open action.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Zoom {
+    /// Whole page fits the window.
+    Fit,
+    /// Page width fits the window.
+    FitWidth,
+    /// Page height fits the window.
+    FitHeight,
+    /// Zoom factor, e.g. 1.5 for 150%.
+    Factor(f32),
+}
+
+/// Viewer preferences ( catalog /ViewerPreferences ), only true flags are written.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ViewerPrefs {
+    /// Hide the viewer tool bars.
+    pub hide_toolbar: bool,
+    /// Hide the viewer menu bar.
+    pub hide_menubar: bool,
+    /// Hide scroll bars and navigation controls.
+    pub hide_window_ui: bool,
+    /// Resize the window to fit the first page.
+    pub fit_window: bool,
+    /// Center the window on the screen.
+    pub center_window: bool,
+    /// Show the document title ( rather than the file name ) in the title bar.
+    pub display_doc_title: bool,
+    /// Page mode when exiting full screen mode ( only used with PageMode::FullScreen ).
+    pub non_full_screen_page_mode: Option<PageMode>,
+}
+
+/// Catalog entries that control how the document is opened.
+#[derive(Clone, Debug, Default)]
+pub struct Viewer {
+    /// Page mode, default ( None ) leaves it to the viewer ( no /PageMode ).
+    pub page_mode: Option<PageMode>,
+    /// Page layout, default ( None ) is the viewer default ( usually SinglePage ).
+    pub page_layout: Option<PageLayout>,
+    /// Page index ( zero based ) and zoom to show when the document is opened.
+    pub open_action: Option<(usize, Zoom)>,
+    /// Viewer preferences.
+    pub prefs: ViewerPrefs,
+}
+
+impl BasicPdfWriter {
+    /// Add viewer entries to the catalog dictionary cat.
+    pub fn viewer_entries(&self, cat: &mut Dict) {
+        let v = &self.viewer;
+        if let Some(m) = v.page_mode {
+            cat.set("PageMode", Object::name(m.name()));
+        }
+        if let Some(l) = v.page_layout {
+            cat.set("PageLayout", Object::name(l.name()));
+        }
+        if let Some((i, zoom)) = v.open_action
+            && let Some(page) = self.kids.get(i).copied()
+        {
+            let page = Object::Ref(page);
+            let action = match zoom {
+                Zoom::Fit => vec![page, Object::name("Fit")],
+                Zoom::FitWidth => vec![page, Object::name("FitH"), Object::Null],
+                Zoom::FitHeight => vec![page, Object::name("FitV"), Object::Null],
+                Zoom::Factor(f) => vec![
+                    page,
+                    Object::name("XYZ"),
+                    Object::Null,
+                    Object::Null,
+                    Object::from(f),
+                ],
+            };
+            cat.set("OpenAction", action);
+        }
+        let p = &v.prefs;
+        let mut prefs = Dict::new();
+        for (k, v) in [
+            ("HideToolbar", p.hide_toolbar),
+            ("HideMenubar", p.hide_menubar),
+            ("HideWindowUI", p.hide_window_ui),
+            ("FitWindow", p.fit_window),
+            ("CenterWindow", p.center_window),
+            ("DisplayDocTitle", p.display_doc_title),
+        ] {
+            if v {
+                prefs.set(k, true);
+            }
+        }
+        if let Some(m) = p.non_full_screen_page_mode {
+            prefs.set("NonFullScreenPageMode", Object::name(m.name()));
+        }
+        if !prefs.is_empty() {
+            cat.set("ViewerPreferences", prefs);
+        }
+    }
+}
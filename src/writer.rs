@@ -1,5 +1,27 @@
+//!# Streaming example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    // Each page is sent to the file as soon as it is complete.
+//!    let file = std::fs::File::create("stream_test.pdf").unwrap();
+//!    let mut w = Writer::default();
+//!    w.b = basic::BasicPdfWriter::new(Box::new(std::io::BufWriter::new(file)));
+//!    for i in 0..1000 {
+//!        html(&mut w, format!("<p>Paragraph {}", i).as_bytes()).unwrap();
+//!    }
+//!    w.finish().unwrap();
+//! ```
+
 use crate::*;
 
+/// Font index of the first of Writer::symbol_fonts ( lower indexes are into Writer::fonts ).
+pub const SYMBOL_FONT: usize = 4;
+
+/// Font with index x ( see SYMBOL_FONT ), a function so that other Writer fields can be borrowed at the same time.
+fn font_at<'a>(fonts: &'a FontFamily, symbol_fonts: &'a [Box<dyn Font>; 2], x: usize) -> &'a dyn Font {
+    &**fonts.iter().chain(symbol_fonts).nth(x).unwrap()
+}
+
 /// Writer - has support for wrapping text, page layout, fonts, etc.
 pub struct Writer {
     /// Underlying Basic Writer
@@ -8,7 +30,9 @@ pub struct Writer {
     pub p: Page,
     /// List of fonts
     pub fonts: FontFamily,
-    /// Index into fonts
+    /// Fallback fonts for characters the current font cannot encode ( default is Symbol and ZapfDingbats ).
+    pub symbol_fonts: [Box<dyn Font>; 2],
+    /// Index into fonts ( from SYMBOL_FONT it indexes symbol_fonts )
     pub cur_font: usize,
     /// Current font size, default is 10
     pub font_size: Px,
@@ -16,10 +40,10 @@ pub struct Writer {
     pub sup: Px,
     /// Writing mode
     pub mode: Mode,
-    /// PDF title
-    pub title: String,
-    /// List of Pages
-    pub pages: Vec<Page>,
+    /// Document information ( title, author, dates etc. )
+    pub info: DocInfo,
+    /// Obj numbers of deferred page counts ( drawn in page footers, written by finish ), keyed by first page of label range.
+    pub page_count_objs: BTreeMap<usize, usize>,
     /// Page is new ( not yet initialised )
     pub new_page: bool,
     /// Line padding ( space between lines ) default is 4
@@ -40,28 +64,49 @@ pub struct Writer {
     pub line_used: MPx,
     /// Line items
     pub line: Vec<Item>,
-    /// Largest font for current line
-    pub max_font_size: Px,
+    /// Height of current line above the baseline ( largest ascent of its items, including sup )
+    pub line_ascent: MPx,
+    /// Depth of current line below the baseline ( largest descent of its items, positive )
+    pub line_descent: MPx,
+    /// Depth of the last line output on the current page ( None if there is no line yet )
+    pub last_descent: Option<MPx>,
     /// Default is zero, set to 1 to center output lines
     pub center: bool,
     /// For fetching fonts and images
     pub fetcher: Option<Box<dyn Fetcher>>,
     /// Cache of images
     pub image_cache: BTreeMap<String,Image>,
+    /// If set, text written in Normal mode is also appended ( e.g. for bookmark titles )
+    pub capture: Option<String>,
+    /// Link active at the end of the last output line ( links may wrap across lines and pages )
+    pub link: Option<Action>,
+    /// Structure element ( tagged PDF ) of the active link.
+    pub link_tag: usize,
+    /// Current structure element ( index into b.structure ), parent of new elements.
+    pub tag: usize,
+    /// Structure element active at the end of the last output line.
+    pub line_tag: usize,
+    /// Page size and margins to be applied from the next page ( see set_page ).
+    pub next_page: Option<PageSetup>,
+    /// Named page setups ( from html @page rules ).
+    pub named_pages: BTreeMap<String, PageSetup>,
+    /// If set, each page gets trim and bleed boxes and printer's marks ( see marks module ).
+    pub print_marks: Option<PrintMarks>,
 }
 
 impl Default for Writer {
     fn default() -> Self {
         Self {
             mode: Mode::Normal,
-            title: String::new(),
+            info: DocInfo::new(),
             b: BasicPdfWriter::default(),
             fonts: helvetica(),
+            symbol_fonts: [symbol(), zapf_dingbats()],
             cur_font: 0,
             font_size: 10,
             sup: 0,
             p: Page::default(),
-            pages: Vec::new(),
+            page_count_objs: BTreeMap::new(),
             new_page: true,
 
             page_width: 600,
@@ -73,43 +118,187 @@ impl Default for Writer {
             margin_bottom: 20,
             line_used: 0,
             line: Vec::new(),
-            max_font_size: 0,
+            line_ascent: 0,
+            line_descent: 0,
+            last_descent: None,
             center: false,
             fetcher: None,
             image_cache: BTreeMap::new(),
+            capture: None,
+            link: None,
+            link_tag: 0,
+            tag: 0,
+            line_tag: 0,
+            next_page: None,
+            named_pages: BTreeMap::new(),
+            print_marks: None,
         }
     }
 }
 
 impl Writer {
     fn init_page(&mut self) {
+        self.p.obj = self.b.obj();
         self.p.width = self.page_width;
         self.p.height = self.page_height;
-        self.p.goto(
-            self.margin_left,
-            self.p.height - self.font_size - self.margin_top,
-        );
+        // The first output line is placed below this by its ascent.
+        self.p.goto(self.margin_left, self.p.height - self.margin_top);
+        self.last_descent = None;
         if self.sup != 0 {
             self.p.set_sup(self.sup);
         }
         self.new_page = false;
     }
 
-    /// Completes current page.
+    /// Completes current page by writing the footer and sending the page to the underlying writer.
     pub fn save_page(&mut self) {
-        let p = std::mem::take(&mut self.p);
-        self.pages.push(p);
+        let mut p = std::mem::take(&mut self.p);
+        self.finish_page(&mut p);
+        self.b.page(&p);
         self.new_page = true;
+        if let Some(s) = self.next_page.take() {
+            self.apply_page_setup(s);
+        }
+    }
+
+    /// Current page size and margins ( or those to be applied from the next page ).
+    pub fn page_setup(&self) -> PageSetup {
+        self.next_page.unwrap_or(PageSetup {
+            width: self.page_width,
+            height: self.page_height,
+            margins: Some([
+                self.margin_top,
+                self.margin_right,
+                self.margin_bottom,
+                self.margin_left,
+            ]),
+        })
+    }
+
+    /// Sets page size and margins starting from the next page ( or the current page if nothing has been output on it ).
+    pub fn set_page(&mut self, s: PageSetup) {
+        if self.new_page {
+            self.apply_page_setup(s);
+        } else {
+            self.next_page = Some(s);
+        }
+    }
+
+    /// Outputs the current line and starts a new page ( does nothing if the current page is empty ).
+    pub fn page_break(&mut self) {
+        if !self.line.is_empty() {
+            self.output_line();
+        }
+        if !self.new_page {
+            self.save_page();
+        }
+    }
+
+    fn apply_page_setup(&mut self, s: PageSetup) {
+        self.page_width = s.width;
+        self.page_height = s.height;
+        if let Some([top, right, bottom, left]) = s.margins {
+            self.margin_top = top;
+            self.margin_right = right;
+            self.margin_bottom = bottom;
+            self.margin_left = left;
+        }
+    }
+
+    /// Writes "Page X of Y" footer and finishes page, Y is a deferred object written by finish.
+    /// Y is the label of the last page in the same label range as X, e.g. "Page ii of iv".
+    fn finish_page(&mut self, p: &mut Page) {
+        self.init_font(0);
+        let page = self.b.kids.len();
+        let range = self.b.labels.iter().rev().find(|r| r.page <= page);
+        let range = range.map_or(0, |r| r.page);
+        let count_obj = match self.page_count_objs.get(&range) {
+            Some(obj) => *obj,
+            None => {
+                let obj = self.b.obj();
+                self.page_count_objs.insert(range, obj);
+                obj
+            }
+        };
+        let font_size = 8;
+        let s = format!("Page {} of ", self.b.page_label(page));
+        let f = &*self.fonts[0];
+        let width: MPx = f.text_width(&s) * font_size as MPx;
+        // In a tagged PDF the footer is an artifact ( not part of the structure ).
+        let tagged = self.b.tagged;
+        if tagged {
+            p.unmark();
+            p.ts.extend_from_slice(b"\n/Artifact BMC");
+        }
+        p.goto(self.margin_left, self.line_pad);
+        if let Err(e) = p.text(f, font_size, &s) {
+            self.b.set_error(e);
+        }
+        if tagged {
+            p.flush_text();
+            p.ts.extend_from_slice(b" EMC");
+        }
+        p.finish();
+        let x = self.margin_left as f32 + width as f32 / 1000.0;
+        let y = self.line_pad;
+        p.xobjs.insert(count_obj);
+        let (bmc, emc): (&[u8], &[u8]) = if tagged {
+            (b"/Artifact BMC ", b" EMC")
+        } else {
+            (b"", b"")
+        };
+        p.os.push(b'\n');
+        p.os.extend_from_slice(bmc);
+        op(&[], "q", &mut p.os);
+        op(&[1.into(), 0.into(), 0.into(), 1.into(), x.into(), y.into()], "cm", &mut p.os);
+        op(&[Object::name(&format!("X{}", count_obj))], "Do", &mut p.os);
+        op(&[], "Q", &mut p.os);
+        p.os.extend_from_slice(emc);
+        if let Some(m) = &self.print_marks {
+            p.os.extend_from_slice(bmc);
+            p.print_marks(m);
+            p.os.extend_from_slice(emc);
+        }
     }
 
     fn init_font(&mut self, x: usize) {
-        let f = &mut self.fonts[x];
+        let f = self.fonts.iter_mut().chain(&mut self.symbol_fonts).nth(x).unwrap();
         f.init(&mut self.b);
     }
 
-    fn width(&self, c: char) -> MPx {
-        let f = &self.fonts[self.cur_font];
-        f.width(c) * self.font_size as MPx
+    fn font(&self, x: usize) -> &dyn Font {
+        font_at(&self.fonts, &self.symbol_fonts, x)
+    }
+
+    fn width(&self, s: &str, f: usize) -> MPx {
+        self.font(f).text_width(s) * self.font_size as MPx
+    }
+
+    /// Splits s into runs of characters with the font used for each run.
+    /// Characters the current font cannot encode use a symbol font if possible.
+    fn runs(&self, s: &str) -> Result<Vec<(String, usize)>> {
+        let font = &self.fonts[self.cur_font];
+        if font.encode(s, &mut Vec::new()).is_ok() {
+            return Ok(vec![(s.to_string(), self.cur_font)]);
+        }
+        let mut runs: Vec<(String, usize)> = Vec::new();
+        for c in s.chars() {
+            let cs = c.encode_utf8(&mut [0; 4]).to_string();
+            let ok = |f: &dyn Font| f.encode(&cs, &mut Vec::new()).is_ok();
+            // The error is for c, the first character no font can encode.
+            let f = match font.encode(&cs, &mut Vec::new()) {
+                Ok(()) => self.cur_font,
+                Err(e) => match self.symbol_fonts.iter().position(|f| ok(&**f)) {
+                    Some(i) => SYMBOL_FONT + i,
+                    None => return Err(e),
+                },
+            };
+            match runs.last_mut() {
+                Some((run, rf)) if *rf == f => run.push(c),
+                _ => runs.push((cs, f)),
+            }
+        }
+        Ok(runs)
     }
 
     fn line_len(&self) -> MPx {
@@ -122,33 +311,31 @@ impl Writer {
         }
     }
 
-    fn wrap_text(&mut self, s: &str) {
+    fn wrap_text(&mut self, runs: Vec<(String, usize)>) {
         self.wrap_init();
 
-        let mut width: MPx = 0;
-        for c in s.chars() {
-            width += self.width(c); // May depend on current font.
-        }
+        // Widths depend on the font, and include kerning.
+        let widths: Vec<MPx> = runs.iter().map(|(s, f)| self.width(s, *f)).collect();
+        let width: MPx = widths.iter().sum();
 
         if self.line_used + width > self.line_len() {
             self.output_line();
-            if s == " " {
+            if runs.len() == 1 && runs[0].0 == " " {
                 return;
             }
         }
         self.line_used += width;
 
-        self.init_font(self.cur_font);
-        if self.font_size > self.max_font_size {
-            self.max_font_size = self.font_size;
-        }
+        for ((s, f), width) in runs.into_iter().zip(widths) {
+            self.init_font(f);
+            let m = self.font(f).metrics();
+            let size = self.font_size as MPx;
+            let sup = self.sup as MPx * 1000;
+            self.line_ascent = self.line_ascent.max(m.ascent as MPx * size + sup);
+            self.line_descent = self.line_descent.max(-m.descent as MPx * size - sup);
 
-        self.line.push(Item::Text(
-            s.to_string(),
-            self.cur_font,
-            self.font_size,
-            width,
-        ));
+            self.line.push(Item::Text(s, f, self.font_size, width));
+        }
     }
 
     fn wrap_image(&mut self, im: Image, width: Px, scale: f32) {
@@ -161,33 +348,65 @@ impl Writer {
         }
 
         self.line_used += width;
+        let height = (im.height as f32 * scale * 1000.0) as MPx;
+        self.line_ascent = self.line_ascent.max(height);
         self.line.push(Item::Img(im, width, scale));
     }
 
+    fn wrap_field(&mut self, f: Field, width: Px, height: Px) {
+        self.wrap_init();
+
+        let width = (width as MPx) * 1000; // Convert width to MPx
+
+        if self.line_used + width > self.line_len() {
+            self.output_line();
+        }
+
+        self.line_used += width;
+        // The field is drawn from 2 below the baseline.
+        self.line_ascent = self.line_ascent.max((height - 2) as MPx * 1000);
+        self.line_descent = self.line_descent.max(2000);
+        self.line.push(Item::Field(f, width, height));
+    }
+
     /// Outputs current line ( consisting of items ).
     pub fn output_line(&mut self) {
         if self.new_page {
             self.init_page();
+        }
+        let cx = if self.center {
+            ((self.line_len() - self.line_used) / 2000) as Px
         } else {
-            let cx = if self.center {
-                ((self.line_len() - self.line_used) / 2000) as Px
-            } else {
-                0
-            };
-            let h = self.max_font_size + self.line_pad;
-            if self.p.y >= h + self.margin_bottom {
-                self.p.td(self.margin_left + cx - self.p.x, -h);
-            } else {
-                self.save_page();
-                self.init_page();
-            }
+            0
+        };
+        // Baseline is below the previous line by its descent, line_pad and the ascent of this line.
+        let ceil = |x: MPx| ((x + 999) / 1000) as Px;
+        let mut h = match self.last_descent {
+            Some(d) => ceil(d + self.line_ascent) + self.line_pad,
+            None => ceil(self.line_ascent),
+        };
+        if self.last_descent.is_some()
+            && self.p.y - h - ceil(self.line_descent) < self.margin_bottom
+        {
+            self.save_page();
+            self.init_page();
+            h = ceil(self.line_ascent);
         }
+        self.p.td(self.margin_left + cx - self.p.x, -h);
+        self.last_descent = Some(self.line_descent);
+        // Space for images, fields and attachments is left using the first font if no font is set yet.
+        self.init_font(0);
         let mut cx: MPx = 0;
+        let mut links = Vec::new(); // Link fragments: start, end, action
+        let mut link_start: MPx = 0;
         for item in &self.line {
             match item {
                 Item::Text(s, f, x, w) => {
-                    let fp = &*self.fonts[*f];
-                    self.p.text(fp, *x, s);
+                    self.b.mark_text(&mut self.p, self.line_tag);
+                    let fp = font_at(&self.fonts, &self.symbol_fonts, *f);
+                    if let Err(e) = self.p.text(fp, *x, s) {
+                        self.b.set_error(e);
+                    }
                     cx += w;
                 }
                 Item::Sup(x) => {
@@ -197,71 +416,263 @@ impl Writer {
                     self.p.flush_text();
                     let x: f32 = (self.p.x as f32) + (cx as f32 / 1000.0);
                     let y = self.p.y as f32;
+                    if self.b.tagged {
+                        self.b.mark_os(&mut self.p, self.line_tag);
+                    }
                     im.draw(&mut self.p, x, y, *scale);
+                    if self.b.tagged {
+                        self.p.os.extend_from_slice(b"\nEMC");
+                    }
                     cx += width;
+                    self.p.ensure_font(&*self.fonts[0], self.font_size);
                     self.p.space(*width);
                 }
+                Item::Field(f, width, height) => {
+                    self.p.flush_text();
+                    let x: f32 = (self.p.x as f32) + (cx as f32 / 1000.0);
+                    let y = self.p.y as f32 - 2.0;
+                    let rect = [x, y, x + *width as f32 / 1000.0, y + *height as f32];
+                    let elem = self.b.tagged.then_some(self.line_tag);
+                    let obj = self.b.field(f, rect, self.p.obj, elem);
+                    self.p.annots.push(obj);
+                    cx += width;
+                    self.p.ensure_font(&*self.fonts[0], self.font_size);
+                    self.p.space(*width);
+                }
+                Item::Attach(fs, desc, width) => {
+                    self.p.flush_text();
+                    let x: f32 = (self.p.x as f32) + (cx as f32 / 1000.0);
+                    let y = self.p.y as f32;
+                    let w = *width as f32 / 1000.0;
+                    let rect = [x, y, x + w, y + w];
+                    let elem = self.b.tagged.then_some(self.line_tag);
+                    let obj = self.b.file_annot(rect, *fs, desc, elem);
+                    self.p.annots.push(obj);
+                    cx += width;
+                    self.p.ensure_font(&*self.fonts[0], self.font_size);
+                    self.p.space(*width);
+                }
+                Item::Bookmark(i) => {
+                    let b = &mut self.b.outline[*i];
+                    b.page = self.p.obj;
+                    b.y = self.p.y + ((self.line_ascent + 999) / 1000) as Px;
+                }
+                Item::Dest(name) => {
+                    let y = self.p.y + ((self.line_ascent + 999) / 1000) as Px;
+                    self.b.dests.insert(name.clone(), (self.p.obj, y));
+                }
+                Item::Link(a) => {
+                    if let Some(a) = self.link.take() {
+                        links.push((link_start, cx, a, self.link_tag));
+                    }
+                    self.link = a.clone();
+                    self.link_tag = self.line_tag;
+                    link_start = cx;
+                }
+                Item::Tag(e, new) => {
+                    if *new {
+                        let parent = self.b.structure[*e].parent;
+                        self.b.structure[parent].kids.push(StructKid::Elem(*e));
+                    }
+                    self.line_tag = *e;
+                }
+            }
+        }
+        if let Some(a) = &self.link {
+            links.push((link_start, cx, a.clone(), self.link_tag));
+        }
+        for (x0, x1, a, e) in links {
+            if x1 > x0 {
+                let x = self.p.x as f32;
+                let y = self.p.y as f32;
+                let rect = [
+                    x + x0 as f32 / 1000.0,
+                    y - self.line_descent as f32 / 1000.0,
+                    x + x1 as f32 / 1000.0,
+                    y + self.line_ascent as f32 / 1000.0,
+                ];
+                let obj = self.b.link(rect, &a, self.b.tagged.then_some(e));
+                self.p.annots.push(obj);
             }
         }
         self.line.clear();
         self.line_used = 0;
-        self.max_font_size = 0;
+        self.line_ascent = 0;
+        self.line_descent = 0;
     }
 
-    /// Writes word-wrapped text if mode is Normal, adds text to title if mode is Title.
-    pub fn text(&mut self, s: &str) {
+    /// Writes word-wrapped text if mode is Normal, adds text to info.title if mode is Title.
+    /// Characters the current font cannot encode are written using symbol_fonts if possible.
+    /// Error if the text cannot be encoded.
+    pub fn text(&mut self, s: &str) -> Result<()> {
         match self.mode {
             Mode::Normal => {
-                self.wrap_text(s);
+                let runs = self.runs(s)?;
+                self.wrap_text(runs);
+                if let Some(c) = &mut self.capture {
+                    *c += s;
+                }
             }
             Mode::Title => {
-                self.title += s;
+                self.info.title += s;
             }
             Mode::Head => {}
         }
+        Ok(())
     }
 
-    fn fetch_image(&mut self, src: &str) -> Option<Image>
+    fn fetch_image(&mut self, src: &str) -> Result<Image>
     {
-        let mut result = None;
         if let Some(im) = self.image_cache.get(src)
         {
-           result = Some(im.clone());
-        }
-        else
-        {
-            let mut bf = std::mem::take(&mut self.fetcher);
-            if let Some(f) = &mut bf {
-                let im = f.image(self, src);
-                self.image_cache.insert( src.to_owned(), im.clone() );
-                result = Some(im);
-            }
-            self.fetcher = bf;
+           return Ok(im.clone());
         }
-        result
+        let mut bf = std::mem::take(&mut self.fetcher);
+        let result = match &mut bf {
+            Some(f) => f.image(self, src),
+            None => Err(Error::Fetch(src.to_string(), "no fetcher in pdf-min::Writer".to_string())),
+        };
+        self.fetcher = bf;
+        let im = result?;
+        self.image_cache.insert( src.to_owned(), im.clone() );
+        Ok(im)
     }   
 
-    /// Write image
-    pub fn image(&mut self, src: &str, awidth: Option<Px>, aheight: Option<Px>) {
-        if let Some(im) = self.fetch_image( src ) {
-            let mut width: Px = im.width;
-            let mut scale: f32 = 1.0;
-            if let Some(awidth) = awidth {
-                scale = awidth as f32 / width as f32;
-                width = awidth;
-            } else if let Some(aheight) = aheight {
-                scale = aheight as f32 / im.height as f32;
-                width = (width as f32 * scale) as Px;
-            }
-            self.wrap_image(im, width, scale);
+    /// Write image, error if the image cannot be fetched.
+    pub fn image(&mut self, src: &str, awidth: Option<Px>, aheight: Option<Px>) -> Result<()> {
+        let im = self.fetch_image( src )?;
+        let mut width: Px = im.width;
+        let mut scale: f32 = 1.0;
+        if let Some(awidth) = awidth {
+            scale = awidth as f32 / width as f32;
+            width = awidth;
+        } else if let Some(aheight) = aheight {
+            scale = aheight as f32 / im.height as f32;
+            width = (width as f32 * scale) as Px;
+        }
+        self.wrap_image(im, width, scale);
+        Ok(())
+    }
+
+    /// Adds a form field of given size to the current line ( see form module ).
+    pub fn field(&mut self, kind: FieldKind, name: &str, tooltip: &str, width: Px, height: Px) {
+        let f = Field {
+            name: name.to_string(),
+            tooltip: tooltip.to_string(),
+            font_size: self.font_size,
+            kind,
+        };
+        let save = self.tag_start("Form", None);
+        self.wrap_field(f, width, height);
+        self.tag_end(save);
+    }
+
+    /// Adds a signature field of given size ( zero for an invisible signature ) to the current line ( see sign module ).
+    /// To be signed by finish, b.signature must be set before the field is written ( when the line is output ).
+    pub fn signature_field(&mut self, name: &str, width: Px, height: Px) {
+        self.field(FieldKind::Signature, name, "", width, height);
+    }
+
+    /// Embeds a file in the PDF ( see attach module ), result is obj number of the file specification.
+    pub fn attach(&mut self, f: &FileSpec) -> usize {
+        self.b.embed_file(f)
+    }
+
+    /// Adds a file attachment icon ( annotation ) to the current line, fs is the result of attach.
+    pub fn file_annotation(&mut self, fs: usize, description: &str) {
+        self.wrap_init();
+        let width = (self.font_size as MPx) * 1000;
+        if self.line_used + width > self.line_len() {
+            self.output_line();
+        }
+        self.line_used += width;
+        // The icon is square and stands on the baseline.
+        self.line_ascent = self.line_ascent.max(width);
+        let save = self.tag_start("Annot", None);
+        self.line.push(Item::Attach(fs, description.to_string(), width));
+        self.tag_end(save);
+    }
+
+    /// Adds a custom XMP metadata property in namespace prefix ( see xmp module ), an XMP metadata stream will be written.
+    pub fn xmp_property(&mut self, prefix: &str, uri: &str, name: &str, value: &str) {
+        self.b
+            .xmp
+            .get_or_insert_with(Xmp::default)
+            .property(prefix, uri, name, value);
+    }
+
+    /// Encrypt the PDF, must be called before any output ( see crypt module ).
+    pub fn encrypt(&mut self, s: &Security) -> Result<()> {
+        self.b.encrypt(s)
+    }
+
+    /// Adds a bookmark at the start of the current line, level 1 is the top level. Result is index into b.outline.
+    pub fn bookmark(&mut self, title: &str, level: usize) -> usize {
+        let i = self.b.outline.len();
+        self.b.outline.push(OutlineItem {
+            title: title.to_string(),
+            level,
+            ..Default::default()
+        });
+        self.line.push(Item::Bookmark(i));
+        i
+    }
+
+    /// Starts a link, following text and images ( up to link_end ) are linked.
+    /// A uri starting with '#' goes to the named destination ( see dest ), otherwise it is an external URI.
+    pub fn link_start(&mut self, uri: &str) {
+        let a = if let Some(name) = uri.strip_prefix('#') {
+            Action::GoTo(name.to_string())
         } else {
-            self.text("error : no fetcher in pdf-min::Writer");
+            Action::Uri(uri.to_string())
+        };
+        self.line.push(Item::Link(Some(a)));
+    }
+
+    /// Ends the current link.
+    pub fn link_end(&mut self) {
+        self.line.push(Item::Link(None));
+    }
+
+    /// Defines a named destination at the start of the current line ( links to it may come before or after ).
+    pub fn dest(&mut self, name: &str) {
+        self.line.push(Item::Dest(name.to_string()));
+    }
+
+    /// Starts a structure element ( tagged PDF ) of type kind ( e.g. "P" ) inside the current element.
+    /// Result is the enclosing element, to be passed to tag_end. Does nothing if b.tagged is not set.
+    pub fn tag_start(&mut self, kind: &'static str, alt: Option<String>) -> usize {
+        let save = self.tag;
+        if self.b.tagged {
+            self.tag = self.b.struct_elem(kind, save, alt);
+            self.line.push(Item::Tag(self.tag, true));
         }
+        save
+    }
+
+    /// Ends the current structure element, save is the result of tag_start.
+    pub fn tag_end(&mut self, save: usize) {
+        if self.tag != save {
+            self.tag = save;
+            self.line.push(Item::Tag(save, false));
+        }
+    }
+
+    /// Starts a page label range ( used for viewer page labels and footers ) at the next page to be started.
+    pub fn page_labels(&mut self, style: LabelStyle, prefix: &str, start: usize) {
+        let page = self.b.kids.len() + if self.new_page { 0 } else { 1 };
+        self.b.labels.retain(|r| r.page < page);
+        self.b.labels.push(PageLabel {
+            page,
+            style,
+            prefix: prefix.to_string(),
+            start,
+        });
     }
 
     /// Adds a space to text.
-    pub fn space(&mut self) {
-        self.text(" ");
+    pub fn space(&mut self) -> Result<()> {
+        self.text(" ")
     }
 
     /// Sets sup
@@ -270,27 +681,56 @@ impl Writer {
         self.sup = sup;
     }
 
-    /// Flushes output line, writes page footers, saves pages, sets title, returns finished PDF as byte slice.
-    pub fn finish(&mut self) -> &[u8] {
-        self.output_line();
-        self.init_font(0);
-        self.save_page();
-        let n = self.pages.len();
-        let mut pnum = 1;
+    /// Flushes output line, saves last page, writes page count and document information, returns finished PDF as byte slice.
+    ///
+    /// When streaming ( b.out is set ) the result is empty. The error is the first error that occurred while writing.
+    pub fn finish(&mut self) -> Result<&[u8]> {
+        // A page break at the end does not leave an empty page, but there is always at least one page.
+        if !self.line.is_empty() || self.b.kids.is_empty() {
+            self.output_line();
+        }
+        if !self.new_page {
+            self.save_page();
+        }
+        self.write_page_count();
+        for f in self.fonts.iter_mut().chain(&mut self.symbol_fonts) {
+            f.finish(&mut self.b);
+        }
+        self.b.finish(&self.info)?;
+        Ok(&self.b.b)
+    }
+
+    /// Writes the deferred page counts as form XObjects.
+    fn write_page_count(&mut self) {
+        let pages = self.b.kids.len();
+        for (start, obj) in std::mem::take(&mut self.page_count_objs) {
+            let next = self.b.labels.iter().find(|r| r.page > start);
+            let end = next.map_or(pages, |r| r.page.min(pages));
+            let last = self.b.page_label(end - 1);
+            self.write_count_obj(obj, &last);
+        }
+    }
+
+    /// Writes a page count ( the label of the last page ) as form XObject obj.
+    fn write_count_obj(&mut self, obj: usize, last: &str) {
         let font_size = 8;
-        #[allow(clippy::explicit_counter_loop)]
-        for p in &mut self.pages {
-            p.goto(self.margin_left, self.line_pad);
-            p.text(
-                &*self.fonts[0],
-                font_size,
-                &format!("Page {} of {}", pnum, n),
-            );
-            p.finish();
-            pnum += 1;
-        }
-        self.b.finish(&self.pages, self.title.as_bytes());
-        &self.b.b
+        let font = self.fonts[0].obj();
+        let mut text = Vec::new();
+        if let Err(e) = self.fonts[0].encode(last, &mut text) {
+            self.b.set_error(e);
+        }
+        let mut data = Vec::new();
+        op(&[], "BT", &mut data);
+        op(&[Object::name(&format!("F{}", font)), font_size.into()], "Tf", &mut data);
+        op(&[Object::String(text)], "Tj", &mut data);
+        op(&[], "ET", &mut data);
+        let fonts = Dict::new().with(&format!("F{}", font), Object::Ref(font));
+        let dict = Dict::new()
+            .with("Type", Object::name("XObject"))
+            .with("Subtype", Object::name("Form"))
+            .with("BBox", Object::nums(&[0.0, -10.0, 100.0, 20.0]))
+            .with("Resources", Dict::new().with("Font", fonts));
+        self.b.stream_obj(obj, &dict, &data);
     }
 }
 
@@ -313,16 +753,28 @@ pub enum Item {
     Sup(Px),
     /// Image, image, width, scale
     Img(Image, MPx, f32),
+    /// Form field, field, width, height
+    Field(Field, MPx, Px),
+    /// File attachment icon, file specification obj number, description, width
+    Attach(usize, String, MPx),
+    /// Bookmark, index into b.outline ( page and position are set when line is output )
+    Bookmark(usize),
+    /// Named destination ( page and position are set when line is output )
+    Dest(String),
+    /// Start ( or end if None ) of link, an annotation is written for each line the link covers
+    Link(Option<Action>),
+    /// Following content belongs to structure element ( tagged PDF ), true if the element is new
+    Tag(usize, bool),
 }
 
 /// Instances can fetch an image or font
 pub trait Fetcher {
-    /// Fetch named image
-    fn image(&mut self, _w: &mut Writer, _name: &str) -> Image {
-        todo!()
+    /// Fetch named image, default is an error.
+    fn image(&mut self, _w: &mut Writer, name: &str) -> Result<Image> {
+        Err(Error::Fetch(name.to_string(), "images are not supported by fetcher".to_string()))
     }
-    /// Fetch specified font
-    fn font(&mut self, _w: &mut Writer, _name: &str) -> Box<dyn Font> {
-        todo!()
+    /// Fetch specified font, default is an error.
+    fn font(&mut self, _w: &mut Writer, name: &str) -> Result<Box<dyn Font>> {
+        Err(Error::Fetch(name.to_string(), "fonts are not supported by fetcher".to_string()))
     }
 }
@@ -0,0 +1,180 @@
+//!# XMP example
+//!
+//! ```
+//!    use pdf_min::*;
+//!    let mut w = Writer::default();
+//!    w.info.author = "J. Smith".to_string();
+//!    w.xmp_property("dam", "http://example.com/ns/dam/1.0/", "Project", "Apollo");
+//!    html(&mut w, b"<title>Brochure</title><body><p>Hello").unwrap();
+//!    let bytes = w.finish().unwrap();
+//!    assert!(bytes.windows(28).any(|x| x == b"<dam:Project>Apollo</dam:Pro"));
+//!    // For PDF/A the custom properties are described by an extension schema.
+//!    let s = xmp::xmp(&w.info, Some(pdfa::Conformance::PdfA2b), w.b.xmp.as_ref());
+//!    assert!(s.contains("<pdfaProperty:name>Project</pdfaProperty:name>"));
+//! ```
+
+use crate::*;
+
+/// Custom XMP namespaces and properties ( standard properties are generated from the document information ).
+#[derive(Clone, Debug, Default)]
+pub struct Xmp {
+    /// Namespaces ( prefix, uri ).
+    pub namespaces: Vec<(String, String)>,
+    /// Simple properties ( qualified name, e.g. "dam:Project", value ).
+    pub properties: Vec<(String, String)>,
+}
+
+impl Xmp {
+    /// Add property name ( without prefix ) in namespace prefix, the namespace is declared if it is new.
+    pub fn property(&mut self, prefix: &str, uri: &str, name: &str, value: &str) {
+        if !self.namespaces.iter().any(|(p, _)| p == prefix) {
+            self.namespaces.push((prefix.to_string(), uri.to_string()));
+        }
+        self.properties
+            .push((format!("{}:{}", prefix, name), value.to_string()));
+    }
+}
+
+/// Append s to XML text with &amp;, &lt; and &gt; escaped.
+pub fn xml_escape(s: &str, to: &mut String) {
+    for c in s.chars() {
+        match c {
+            '&' => to.push_str("&amp;"),
+            '<' => to.push_str("&lt;"),
+            '>' => to.push_str("&gt;"),
+            '"' => to.push_str("&quot;"),
+            _ => to.push(c),
+        }
+    }
+}
+
+/// Append simple XMP property, e.g. &lt;pdf:Producer&gt;pdf-min&lt;/pdf:Producer&gt;
+fn property(name: &str, value: &str, to: &mut String) {
+    *to += &format!("<{}>", name);
+    xml_escape(value, to);
+    *to += &format!("</{}>\n", name);
+}
+
+/// Append XMP language alternative property ( e.g. dc:title ).
+fn alt(name: &str, value: &str, to: &mut String) {
+    *to += &format!("<{}><rdf:Alt><rdf:li xml:lang=\"x-default\">", name);
+    xml_escape(value, to);
+    *to += &format!("</rdf:li></rdf:Alt></{}>\n", name);
+}
+
+/// Append PDF/A extension schema descriptions for the custom namespaces ( PDF/A only allows custom properties that are described ).
+fn extension_schemas(custom: &Xmp, to: &mut String) {
+    if custom.properties.is_empty() {
+        return;
+    }
+    *to += "<rdf:Description rdf:about=\"\"";
+    *to += " xmlns:pdfaExtension=\"http://www.aiim.org/pdfa/ns/extension/\"";
+    *to += " xmlns:pdfaSchema=\"http://www.aiim.org/pdfa/ns/schema#\"";
+    *to += " xmlns:pdfaProperty=\"http://www.aiim.org/pdfa/ns/property#\">\n";
+    *to += "<pdfaExtension:schemas><rdf:Bag>\n";
+    for (prefix, uri) in &custom.namespaces {
+        let mut names: Vec<&str> = custom
+            .properties
+            .iter()
+            .filter_map(|(name, _)| name.strip_prefix(prefix.as_str())?.strip_prefix(':'))
+            .collect();
+        names.sort();
+        names.dedup();
+        if names.is_empty() {
+            continue;
+        }
+        *to += "<rdf:li rdf:parseType=\"Resource\">\n";
+        property("pdfaSchema:schema", &format!("{} properties", prefix), to);
+        property("pdfaSchema:namespaceURI", uri, to);
+        property("pdfaSchema:prefix", prefix, to);
+        *to += "<pdfaSchema:property><rdf:Seq>\n";
+        for name in names {
+            *to += "<rdf:li rdf:parseType=\"Resource\">\n";
+            property("pdfaProperty:name", name, to);
+            property("pdfaProperty:valueType", "Text", to);
+            property("pdfaProperty:category", "external", to);
+            property("pdfaProperty:description", name, to);
+            *to += "</rdf:li>\n";
+        }
+        *to += "</rdf:Seq></pdfaSchema:property>\n</rdf:li>\n";
+    }
+    *to += "</rdf:Bag></pdfaExtension:schemas>\n</rdf:Description>\n";
+}
+
+/// XMP packet for document information and custom properties, info dates should be set ( so they match the Info dictionary ).
+pub fn xmp(info: &DocInfo, conformance: Option<Conformance>, custom: Option<&Xmp>) -> String {
+    let mut s = String::new();
+    s += "<?xpacket begin=\"\u{feff}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\n";
+    s += "<x:xmpmeta xmlns:x=\"adobe:ns:meta/\">\n";
+    s += "<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n";
+    s += "<rdf:Description rdf:about=\"\"";
+    s += " xmlns:dc=\"http://purl.org/dc/elements/1.1/\"";
+    s += " xmlns:xmp=\"http://ns.adobe.com/xap/1.0/\"";
+    s += " xmlns:pdf=\"http://ns.adobe.com/pdf/1.3/\"";
+    if conformance.is_some() {
+        s += " xmlns:pdfaid=\"http://www.aiim.org/pdfa/ns/id/\"";
+    }
+    for (prefix, uri) in custom.iter().flat_map(|x| &x.namespaces) {
+        s += &format!(" xmlns:{}=\"", prefix);
+        xml_escape(uri, &mut s);
+        s += "\"";
+    }
+    s += ">\n";
+    if !info.title.is_empty() {
+        alt("dc:title", &info.title, &mut s);
+    }
+    if !info.author.is_empty() {
+        s += "<dc:creator><rdf:Seq><rdf:li>";
+        xml_escape(&info.author, &mut s);
+        s += "</rdf:li></rdf:Seq></dc:creator>\n";
+    }
+    if !info.subject.is_empty() {
+        alt("dc:description", &info.subject, &mut s);
+    }
+    if !info.keywords.is_empty() {
+        property("pdf:Keywords", &info.keywords, &mut s);
+    }
+    if !info.producer.is_empty() {
+        property("pdf:Producer", &info.producer, &mut s);
+    }
+    if !info.creator.is_empty() {
+        property("xmp:CreatorTool", &info.creator, &mut s);
+    }
+    if let Some(d) = info.creation_date {
+        property("xmp:CreateDate", &d.iso(), &mut s);
+    }
+    if let Some(d) = info.mod_date {
+        property("xmp:ModifyDate", &d.iso(), &mut s);
+        property("xmp:MetadataDate", &d.iso(), &mut s);
+    }
+    if let Some(c) = conformance {
+        property("pdfaid:part", &c.part().to_string(), &mut s);
+        property("pdfaid:conformance", c.level(), &mut s);
+    }
+    for (name, value) in custom.iter().flat_map(|x| &x.properties) {
+        property(name, value, &mut s);
+    }
+    s += "</rdf:Description>\n";
+    if let (Some(_), Some(custom)) = (conformance, custom) {
+        extension_schemas(custom, &mut s);
+    }
+    s += "</rdf:RDF>\n</x:xmpmeta>\n";
+    s += "<?xpacket end=\"w\"?>";
+    s
+}
+
+impl BasicPdfWriter {
+    /// Write XMP metadata stream ( not compressed, so it can be found without parsing the PDF ), result is obj number.
+    pub fn metadata(&mut self, info: &DocInfo) -> usize {
+        let x = xmp(info, self.conformance, self.xmp.as_ref());
+        let obj = self.obj();
+        self.raw_stream_obj(
+            obj,
+            &Dict::new()
+                .with("Type", Object::name("Metadata"))
+                .with("Subtype", Object::name("XML")),
+            x.as_bytes(),
+        );
+        obj
+    }
+}